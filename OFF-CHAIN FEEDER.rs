@@ -1,15 +1,38 @@
 import time
 import hashlib
+import sys
 import nacl.signing
 import requests
+from stellar_sdk import StrKey
 
-PEG_VALUE = 314_159  # example
+PEG_VALUE = 314_159_000_000  # example: $314,159 with 6 decimals
 TIMESTAMP = int(time.time())
 
+# Must match the deployment the feeder reports to
+CONTRACT_ID = "C..."  # PiPegOracleContract address (strkey)
+NETWORK_PASSPHRASE = "Public Global Stellar Network ; September 2015"
+ROUND = int(sys.argv[1])  # current open round, read from the contract's round()
+NONCE = int(sys.argv[2])  # this feeder's feeder_nonce(); bumps per accepted submission
+
+DOMAIN = b"PI_PEG_ORACLE_V1"
+
 # load feeder private key
 sk = nacl.signing.SigningKey(open("feeder.sk", "rb").read())
 
+# ScVal::Address(ScAddress::Contract(id)) in XDR, as the contract encodes it
+contract_xdr = (
+    (18).to_bytes(4, "big") +  # SCV_ADDRESS
+    (1).to_bytes(4, "big") +   # SC_ADDRESS_TYPE_CONTRACT
+    StrKey.decode_contract(CONTRACT_ID)
+)
+network_id = hashlib.sha256(NETWORK_PASSPHRASE.encode()).digest()
+
 payload = (
+    DOMAIN +
+    contract_xdr +
+    network_id +
+    ROUND.to_bytes(4, "big") +
+    NONCE.to_bytes(8, "big") +
     PEG_VALUE.to_bytes(16, "big") +
     TIMESTAMP.to_bytes(8, "big")
 )
@@ -18,6 +41,10 @@ hash_bytes = hashlib.sha256(payload).digest()
 signature = sk.sign(hash_bytes).signature
 
 # send to blockchain (RPC / SDK)
+# commit_peg(feeder, round, nonce, peg, timestamp, signature); feeder must be registered
+print("feeder:", sk.verify_key.encode().hex())
+print("round:", ROUND)
+print("nonce:", NONCE)
 print("peg:", PEG_VALUE)
 print("timestamp:", TIMESTAMP)
 print("signature:", signature.hex())
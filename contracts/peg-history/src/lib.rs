@@ -0,0 +1,161 @@
+#![no_std]
+
+//! Peg history shared by the peg oracles: a ring buffer of committed
+//! observations in the calling contract's storage, with point lookups and
+//! a time-weighted average over it.
+
+use soroban_sdk::{contracttype, symbol_short, Env, Symbol, Vec};
+
+//
+// =====================
+// ERROR
+// =====================
+//
+/// Converted into each oracle's own error enum, which keeps its codes.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum HistoryError {
+    Empty,
+    InvalidWindow,
+    InsufficientHistory,
+}
+
+//
+// =====================
+// RING BUFFER
+// =====================
+//
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Observation {
+    pub value: i128,
+    pub timestamp: u64,
+}
+
+// Ring buffer size; the oldest observation is overwritten once full.
+pub const HISTORY_CAPACITY: u32 = 64;
+
+// ~5s ledgers: 17_280 ledgers per day. Observations are persistent entries,
+// extended on every write and read like the account entries in src/.
+const DAY_IN_LEDGERS: u32 = 17_280;
+pub const HISTORY_BUMP_AMOUNT: u32 = 90 * DAY_IN_LEDGERS;
+pub const HISTORY_LIFETIME_THRESHOLD: u32 =
+    HISTORY_BUMP_AMOUNT - 30 * DAY_IN_LEDGERS;
+
+/// Number of observations ever recorded; the latest is `count - 1`.
+pub fn count(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&Symbol::new(env, "obs_count"))
+        .unwrap_or(0)
+}
+
+// Persistent key of the slot observation `index` is kept in.
+fn slot(index: u32) -> (Symbol, u32) {
+    (symbol_short!("obs"), index % HISTORY_CAPACITY)
+}
+
+fn bump(env: &Env, slot: &(Symbol, u32)) {
+    env.storage().persistent().extend_ttl(
+        slot,
+        HISTORY_LIFETIME_THRESHOLD,
+        HISTORY_BUMP_AMOUNT,
+    );
+}
+
+pub fn record(env: &Env, value: i128, timestamp: u64) {
+    let count = count(env);
+    let slot = slot(count);
+
+    env.storage()
+        .persistent()
+        .set(&slot, &Observation { value, timestamp });
+    bump(env, &slot);
+    env.storage().instance().set(
+        &Symbol::new(env, "obs_count"),
+        &(count + 1),
+    );
+}
+
+/// Retained observations, oldest first.
+pub fn observations(env: &Env) -> Vec<Observation> {
+    let count = count(env);
+    let mut out = Vec::new(env);
+
+    for i in count.saturating_sub(HISTORY_CAPACITY)..count {
+        let slot = slot(i);
+        if let Some(observation) = env.storage().persistent().get(&slot) {
+            bump(env, &slot);
+            out.push_back(observation);
+        }
+    }
+
+    out
+}
+
+//
+// =====================
+// QUERIES
+// =====================
+//
+/// Peg in effect at `timestamp`: the latest observation not after it.
+pub fn peg_at(env: &Env, timestamp: u64) -> Result<i128, HistoryError> {
+    let history = observations(env);
+    if history.is_empty() {
+        return Err(HistoryError::Empty);
+    }
+
+    let mut i = history.len();
+    while i > 0 {
+        i -= 1;
+        let observation = history.get_unchecked(i);
+        if observation.timestamp <= timestamp {
+            return Ok(observation.value);
+        }
+    }
+
+    Err(HistoryError::InsufficientHistory)
+}
+
+/// Time-weighted average over the last `window_secs`, treating each
+/// observation as the peg until the next one replaced it.
+pub fn twap(env: &Env, window_secs: u64) -> Result<i128, HistoryError> {
+    if window_secs == 0 {
+        return Err(HistoryError::InvalidWindow);
+    }
+
+    let history = observations(env);
+    if history.is_empty() {
+        return Err(HistoryError::Empty);
+    }
+
+    let now = env.ledger().timestamp();
+    let start = now
+        .checked_sub(window_secs)
+        .ok_or(HistoryError::InsufficientHistory)?;
+
+    if history.get_unchecked(0).timestamp > start {
+        return Err(HistoryError::InsufficientHistory);
+    }
+
+    let mut weighted: i128 = 0;
+    let mut end = now;
+    let mut i = history.len();
+    while i > 0 {
+        i -= 1;
+        let observation = history.get_unchecked(i);
+        let from = observation.timestamp.max(start);
+
+        if from < end {
+            weighted += observation.value * (end - from) as i128;
+            end = from;
+        }
+        if observation.timestamp <= start {
+            break;
+        }
+    }
+
+    Ok(weighted / window_secs as i128)
+}
+
+#[cfg(test)]
+mod test;
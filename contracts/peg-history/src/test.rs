@@ -0,0 +1,126 @@
+#![cfg(test)]
+
+use super::*;
+use soroban_sdk::{
+    contract, contractimpl,
+    testutils::{storage::Persistent as _, Ledger},
+    Address,
+};
+
+const PEG: i128 = 314_159_000_000;
+const START: u64 = 10_000;
+
+// The history lives in contract storage, so tests run inside a host.
+#[contract]
+pub struct Host;
+
+#[contractimpl]
+impl Host {
+    pub fn len(env: Env) -> u32 {
+        observations(&env).len()
+    }
+}
+
+fn setup(env: &Env) -> Address {
+    env.ledger().with_mut(|li| li.timestamp = START);
+    env.register(Host, ())
+}
+
+fn advance(env: &Env, secs: u64) {
+    env.ledger().with_mut(|li| li.timestamp += secs);
+}
+
+fn record_now(env: &Env, host: &Address, value: i128) {
+    env.as_contract(host, || record(env, value, env.ledger().timestamp()));
+}
+
+#[test]
+fn test_twap_window_edges() {
+    let env = Env::default();
+    let host = setup(&env);
+    let twap = |window| env.as_contract(&host, || twap(&env, window));
+    let peg_at = |at| env.as_contract(&host, || peg_at(&env, at));
+
+    assert_eq!(twap(0), Err(HistoryError::InvalidWindow));
+    assert_eq!(twap(60), Err(HistoryError::Empty));
+    assert_eq!(peg_at(START), Err(HistoryError::Empty));
+
+    let high = PEG * 105 / 100;
+    record_now(&env, &host, PEG);
+    advance(&env, 100);
+    record_now(&env, &host, high);
+    advance(&env, 100);
+
+    // The oldest observation may start exactly at the window edge
+    assert_eq!(twap(200), Ok((PEG + high) / 2));
+    assert_eq!(twap(150), Ok((high * 100 + PEG * 50) / 150));
+    assert_eq!(twap(50), Ok(high));
+    for window in [201, START + 201] {
+        assert_eq!(twap(window), Err(HistoryError::InsufficientHistory));
+    }
+
+    assert_eq!(peg_at(START - 1), Err(HistoryError::InsufficientHistory));
+    assert_eq!(peg_at(START), Ok(PEG));
+    assert_eq!(peg_at(START + 99), Ok(PEG));
+    assert_eq!(peg_at(START + 100), Ok(high));
+}
+
+#[test]
+fn test_history_wraps_around() {
+    let env = Env::default();
+    let host = setup(&env);
+
+    for i in 0..HISTORY_CAPACITY + 6 {
+        record_now(&env, &host, PEG + i as i128);
+        advance(&env, 10);
+    }
+
+    // The first six observations have been overwritten
+    let history = env.as_contract(&host, || observations(&env));
+    assert_eq!(history.len(), HISTORY_CAPACITY);
+    assert_eq!(
+        history.get_unchecked(0),
+        Observation { value: PEG + 6, timestamp: START + 60 }
+    );
+    assert_eq!(history.last_unchecked().value, PEG + 69);
+
+    env.as_contract(&host, || {
+        assert_eq!(peg_at(&env, START + 60), Ok(PEG + 6));
+        assert_eq!(
+            peg_at(&env, START + 59),
+            Err(HistoryError::InsufficientHistory)
+        );
+
+        // Ten seconds each for PEG + 6 ..= PEG + 69
+        assert_eq!(twap(&env, 640), Ok(PEG + 37));
+        assert_eq!(
+            twap(&env, 641),
+            Err(HistoryError::InsufficientHistory)
+        );
+    });
+}
+
+#[test]
+fn test_history_entries_are_bumped() {
+    let env = Env::default();
+    let host = setup(&env);
+    let client = HostClient::new(&env, &host);
+    let ttl = || {
+        env.as_contract(&host, || {
+            env.storage().persistent().get_ttl(&slot(0))
+        })
+    };
+
+    record_now(&env, &host, PEG);
+    assert_eq!(ttl(), HISTORY_BUMP_AMOUNT);
+
+    env.ledger().with_mut(|li| {
+        li.sequence_number += HISTORY_BUMP_AMOUNT - HISTORY_LIFETIME_THRESHOLD
+    });
+    assert_eq!(ttl(), HISTORY_LIFETIME_THRESHOLD);
+    env.ledger().with_mut(|li| li.sequence_number += 1);
+
+    // Reading the history falls under the threshold and extends it again
+    assert_eq!(client.len(), 1);
+    assert_eq!(ttl(), HISTORY_BUMP_AMOUNT);
+}
@@ -0,0 +1,548 @@
+#![no_std]
+
+use soroban_sdk::{
+    contract, contracterror, contractimpl, contracttype, symbol_short,
+    Env, Address, Symbol, BytesN, Vec,
+};
+use peg_history::HistoryError;
+
+//
+// =====================
+// ERROR
+// =====================
+//
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum PegOracleError {
+    Unauthorized = 1,
+    PegNotSet = 2,
+    PegOutOfRange = 3,
+    AlreadyInitialized = 4,
+    InvalidWindow = 5,
+    InsufficientHistory = 6,
+    OraclePaused = 7,
+    InvalidMaxChange = 8,
+    GuardianNotSet = 9,
+    PegStale = 10,
+    InvalidMaxAge = 11,
+}
+
+const CONTRACT_VERSION: u32 = 1;
+
+// Largest move from the window's reference peg before the breaker trips.
+const DEFAULT_MAX_CHANGE_BPS: u32 = 1_000;
+
+// The reference peg only moves once per window, so a run of small updates
+// cannot walk the peg past max_change_bps either.
+const CHANGE_WINDOW_SECS: u64 = 3_600;
+
+// Same scale as PiCoinData.peg_value: 314_159_000_000 = $314,159.
+const PEG_DECIMALS: u32 = 6;
+
+// get_peg reports PegStale once the last update is older than this.
+const DEFAULT_MAX_AGE_SECS: u64 = 3_600;
+
+//
+// =====================
+// PEG DATA
+// =====================
+//
+/// Latest committed peg and the metadata needed to judge it.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PegData {
+    pub value: i128,
+    pub decimals: u32,
+    pub timestamp: u64,
+    pub round_id: u32,
+    pub source_count: u32,
+}
+
+/// `latestRoundData`-shaped view of the latest round.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RoundData {
+    pub round_id: u32,
+    pub answer: i128,
+    pub started_at: u64,
+    pub updated_at: u64,
+    pub answered_in_round: u32,
+}
+
+//
+// =====================
+// HISTORY
+// =====================
+//
+pub use peg_history::Observation;
+
+impl From<HistoryError> for PegOracleError {
+    fn from(err: HistoryError) -> Self {
+        match err {
+            HistoryError::Empty => PegOracleError::PegNotSet,
+            HistoryError::InvalidWindow => PegOracleError::InvalidWindow,
+            HistoryError::InsufficientHistory => {
+                PegOracleError::InsufficientHistory
+            }
+        }
+    }
+}
+
+//
+// =====================
+// CONTRACT
+// =====================
+//
+#[contract]
+pub struct PiPegOracleContract;
+
+#[contractimpl]
+impl PiPegOracleContract {
+
+    // -----------------
+    // INIT
+    // -----------------
+    pub fn initialize(
+        env: Env,
+        admin: Address,
+        target_peg: i128, // example: 314_159_000_000 = $314,159
+    ) -> Result<(), PegOracleError> {
+        if env.storage().instance().has(&Symbol::new(&env, "admin")) {
+            return Err(PegOracleError::AlreadyInitialized);
+        }
+
+        admin.require_auth();
+
+        env.storage().instance().set(
+            &Symbol::new(&env, "admin"),
+            &admin,
+        );
+
+        env.storage().instance().set(
+            &Symbol::new(&env, "target_peg"),
+            &target_peg,
+        );
+
+        env.storage().instance().set(
+            &Symbol::new(&env, "version"),
+            &CONTRACT_VERSION,
+        );
+
+        env.events().publish(
+            (symbol_short!("init"), admin),
+            target_peg,
+        );
+
+        Ok(())
+    }
+
+    // -----------------
+    // UPGRADE (ADMIN)
+    // -----------------
+    pub fn version(env: Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&Symbol::new(&env, "version"))
+            .unwrap_or(0)
+    }
+
+    pub fn upgrade(
+        env: Env,
+        new_wasm_hash: BytesN<32>,
+    ) -> Result<(), PegOracleError> {
+        Self::require_admin(&env)?;
+
+        env.deployer().update_current_contract_wasm(new_wasm_hash);
+
+        Ok(())
+    }
+
+    pub fn migrate(env: Env) -> Result<u32, PegOracleError> {
+        Self::require_admin(&env)?;
+
+        if Self::version(env.clone()) < CONTRACT_VERSION {
+            env.storage().instance().set(
+                &Symbol::new(&env, "version"),
+                &CONTRACT_VERSION,
+            );
+        }
+
+        Ok(CONTRACT_VERSION)
+    }
+
+    // -----------------
+    // UPDATE PEG (ORACLE FEED)
+    // -----------------
+    /// An update moving the peg by more than `max_change_bps` from the
+    /// reference peg of the current window is not applied: it pauses the
+    /// feed and is held for the guardian instead.
+    pub fn update_peg(
+        env: Env,
+        new_peg: i128,
+    ) -> Result<(), PegOracleError> {
+        Self::require_admin(&env)?;
+
+        // Simple sanity check (anti oracle attack)
+        if new_peg <= 0 {
+            return Err(PegOracleError::PegOutOfRange);
+        }
+
+        if Self::is_paused(env.clone()) {
+            return Err(PegOracleError::OraclePaused);
+        }
+
+        let previous: Option<i128> = env
+            .storage()
+            .instance()
+            .get(&Symbol::new(&env, "current_peg"));
+
+        if let Some(previous) = previous {
+            let reference = Self::reference_peg(&env, previous);
+            // A move too large to measure is past any limit.
+            let change_bps = new_peg
+                .checked_sub(reference)
+                .and_then(i128::checked_abs)
+                .and_then(|diff| diff.checked_mul(10_000))
+                .map_or(i128::MAX, |scaled| scaled / reference);
+
+            if change_bps > Self::max_change_bps(env.clone()) as i128 {
+                env.storage().instance().set(
+                    &Symbol::new(&env, "paused"),
+                    &true,
+                );
+                env.storage().instance().set(
+                    &Symbol::new(&env, "pending_peg"),
+                    &new_peg,
+                );
+
+                env.events().publish(
+                    (symbol_short!("peg"), symbol_short!("trip")),
+                    (reference, new_peg, change_bps),
+                );
+
+                return Ok(());
+            }
+        }
+
+        Self::commit_peg(&env, new_peg);
+
+        Ok(())
+    }
+
+    fn commit_peg(env: &Env, new_peg: i128) {
+        env.storage().instance().set(
+            &Symbol::new(env, "current_peg"),
+            &new_peg,
+        );
+        env.storage().instance().set(
+            &Symbol::new(env, "updated_at"),
+            &env.ledger().timestamp(),
+        );
+        peg_history::record(env, new_peg, env.ledger().timestamp());
+
+        env.events().publish(
+            (symbol_short!("peg"), symbol_short!("update")),
+            (new_peg, env.ledger().timestamp()),
+        );
+    }
+
+    // Peg at the start of the current window; re-anchored to `current`
+    // once the window has passed.
+    fn reference_peg(env: &Env, current: i128) -> i128 {
+        let key = Symbol::new(env, "ref_peg");
+        let now = env.ledger().timestamp();
+        let stored: Option<(i128, u64)> = env.storage().instance().get(&key);
+
+        match stored {
+            Some((peg, since))
+                if now < since.saturating_add(CHANGE_WINDOW_SECS) =>
+            {
+                peg
+            }
+            _ => {
+                env.storage().instance().set(&key, &(current, now));
+                current
+            }
+        }
+    }
+
+    fn require_admin(env: &Env) -> Result<Address, PegOracleError> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&Symbol::new(env, "admin"))
+            .ok_or(PegOracleError::Unauthorized)?;
+
+        admin.require_auth();
+
+        Ok(admin)
+    }
+
+    // -----------------
+    // CIRCUIT BREAKER
+    // -----------------
+    /// Replacing a sitting guardian takes its auth as well as the
+    /// admin's. Breaker settings are frozen while the feed is paused.
+    pub fn set_guardian(
+        env: Env,
+        guardian: Address,
+    ) -> Result<(), PegOracleError> {
+        Self::require_admin(&env)?;
+
+        if Self::is_paused(env.clone()) {
+            return Err(PegOracleError::OraclePaused);
+        }
+
+        if let Some(current) = Self::guardian(env.clone()) {
+            current.require_auth();
+        }
+
+        env.storage().instance().set(
+            &Symbol::new(&env, "guardian"),
+            &guardian,
+        );
+
+        env.events().publish(
+            (symbol_short!("breaker"), symbol_short!("guardian")),
+            guardian,
+        );
+
+        Ok(())
+    }
+
+    pub fn set_max_change_bps(
+        env: Env,
+        max_change_bps: u32,
+    ) -> Result<(), PegOracleError> {
+        Self::require_admin(&env)?;
+
+        if Self::is_paused(env.clone()) {
+            return Err(PegOracleError::OraclePaused);
+        }
+
+        if max_change_bps == 0 || max_change_bps > 10_000 {
+            return Err(PegOracleError::InvalidMaxChange);
+        }
+
+        env.storage().instance().set(
+            &Symbol::new(&env, "max_change"),
+            &max_change_bps,
+        );
+
+        env.events().publish(
+            (symbol_short!("breaker"), symbol_short!("max_chg")),
+            max_change_bps,
+        );
+
+        Ok(())
+    }
+
+    /// Lifts the pause. With `apply_pending` the guardian vouches for the
+    /// update that tripped the breaker and it becomes the current peg.
+    pub fn resume(
+        env: Env,
+        apply_pending: bool,
+    ) -> Result<(), PegOracleError> {
+        let guardian = Self::guardian(env.clone())
+            .ok_or(PegOracleError::GuardianNotSet)?;
+
+        guardian.require_auth();
+
+        let pending: Option<i128> = env
+            .storage()
+            .instance()
+            .get(&Symbol::new(&env, "pending_peg"));
+
+        env.storage()
+            .instance()
+            .remove(&Symbol::new(&env, "paused"));
+        env.storage()
+            .instance()
+            .remove(&Symbol::new(&env, "pending_peg"));
+
+        let applied = if apply_pending { pending } else { None };
+        if let Some(peg) = applied {
+            // The vouched peg becomes the next window's reference.
+            env.storage()
+                .instance()
+                .remove(&Symbol::new(&env, "ref_peg"));
+            Self::commit_peg(&env, peg);
+        }
+
+        env.events().publish(
+            (symbol_short!("breaker"), symbol_short!("resume")),
+            (guardian, applied),
+        );
+
+        Ok(())
+    }
+
+    pub fn is_paused(env: Env) -> bool {
+        env.storage()
+            .instance()
+            .get(&Symbol::new(&env, "paused"))
+            .unwrap_or(false)
+    }
+
+    pub fn guardian(env: Env) -> Option<Address> {
+        env.storage()
+            .instance()
+            .get(&Symbol::new(&env, "guardian"))
+    }
+
+    pub fn max_change_bps(env: Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&Symbol::new(&env, "max_change"))
+            .unwrap_or(DEFAULT_MAX_CHANGE_BPS)
+    }
+
+    /// Update held back by the last trip, if the feed is still paused.
+    pub fn pending_peg(env: Env) -> Option<i128> {
+        env.storage()
+            .instance()
+            .get(&Symbol::new(&env, "pending_peg"))
+    }
+
+    // -----------------
+    // VERIFY PEG
+    // -----------------
+    /// Whether the peg is within `tolerance` of the target. Goes through
+    /// `get_peg`, so a stale peg is `PegStale` rather than a verdict.
+    pub fn verify_peg(
+        env: Env,
+        tolerance: i128, // allowed deviation
+    ) -> Result<bool, PegOracleError> {
+        let target: i128 = env
+            .storage()
+            .instance()
+            .get(&Symbol::new(&env, "target_peg"))
+            .ok_or(PegOracleError::PegNotSet)?;
+
+        let current = Self::get_peg(env.clone())?.value;
+
+        let diff = if current > target {
+            current - target
+        } else {
+            target - current
+        };
+
+        Ok(diff <= tolerance)
+    }
+
+    // -----------------
+    // READ PEG
+    // -----------------
+    /// Latest peg, or `PegStale` once it is older than `max_age`.
+    pub fn get_peg(env: Env) -> Result<PegData, PegOracleError> {
+        let data = Self::peg_data(&env)?;
+        let age = env.ledger().timestamp().saturating_sub(data.timestamp);
+
+        if age > Self::max_age(env.clone()) {
+            return Err(PegOracleError::PegStale);
+        }
+
+        Ok(data)
+    }
+
+    /// Latest round without a staleness check; consumers compare
+    /// `updated_at` against their own tolerance.
+    pub fn latest_round_data(env: Env) -> Result<RoundData, PegOracleError> {
+        let data = Self::peg_data(&env)?;
+
+        Ok(RoundData {
+            round_id: data.round_id,
+            answer: data.value,
+            started_at: data.timestamp,
+            updated_at: data.timestamp,
+            answered_in_round: data.round_id,
+        })
+    }
+
+    pub fn decimals(_env: Env) -> u32 {
+        PEG_DECIMALS
+    }
+
+    pub fn max_age(env: Env) -> u64 {
+        env.storage()
+            .instance()
+            .get(&Symbol::new(&env, "max_age"))
+            .unwrap_or(DEFAULT_MAX_AGE_SECS)
+    }
+
+    pub fn set_max_age(
+        env: Env,
+        max_age_secs: u64,
+    ) -> Result<(), PegOracleError> {
+        Self::require_admin(&env)?;
+
+        if max_age_secs == 0 {
+            return Err(PegOracleError::InvalidMaxAge);
+        }
+
+        env.storage().instance().set(
+            &Symbol::new(&env, "max_age"),
+            &max_age_secs,
+        );
+
+        env.events().publish(
+            (symbol_short!("peg"), symbol_short!("max_age")),
+            max_age_secs,
+        );
+
+        Ok(())
+    }
+
+    fn peg_data(env: &Env) -> Result<PegData, PegOracleError> {
+        let value: i128 = env
+            .storage()
+            .instance()
+            .get(&Symbol::new(env, "current_peg"))
+            .ok_or(PegOracleError::PegNotSet)?;
+        let timestamp: u64 = env
+            .storage()
+            .instance()
+            .get(&Symbol::new(env, "updated_at"))
+            .ok_or(PegOracleError::PegNotSet)?;
+
+        Ok(PegData {
+            value,
+            decimals: PEG_DECIMALS,
+            timestamp,
+            round_id: peg_history::count(env),
+            source_count: 1,
+        })
+    }
+
+    pub fn last_update(env: Env) -> Result<u64, PegOracleError> {
+        env.storage()
+            .instance()
+            .get(&Symbol::new(&env, "updated_at"))
+            .ok_or(PegOracleError::PegNotSet)
+    }
+
+    // -----------------
+    // PEG HISTORY
+    // -----------------
+    pub fn history(env: Env) -> Vec<Observation> {
+        peg_history::observations(&env)
+    }
+
+    /// Peg in effect at `timestamp`: the latest observation not after it.
+    pub fn get_peg_at(
+        env: Env,
+        timestamp: u64,
+    ) -> Result<i128, PegOracleError> {
+        Ok(peg_history::peg_at(&env, timestamp)?)
+    }
+
+    /// Time-weighted average over the last `window_secs`, treating each
+    /// observation as the peg until the next one replaced it.
+    pub fn twap(env: Env, window_secs: u64) -> Result<i128, PegOracleError> {
+        Ok(peg_history::twap(&env, window_secs)?)
+    }
+}
+
+#[cfg(test)]
+mod test;
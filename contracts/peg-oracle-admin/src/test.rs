@@ -0,0 +1,207 @@
+#![cfg(test)]
+
+use super::*;
+use soroban_sdk::testutils::{Address as _, Ledger};
+
+const PEG: i128 = 314_159_000_000;
+const START: u64 = 10_000;
+
+fn setup(env: &Env) -> (PiPegOracleContractClient<'_>, Address) {
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.timestamp = START);
+
+    let contract_id = env.register(PiPegOracleContract, ());
+    let client = PiPegOracleContractClient::new(env, &contract_id);
+    let admin = Address::generate(env);
+
+    client.initialize(&admin, &PEG);
+
+    (client, admin)
+}
+
+fn advance(env: &Env, secs: u64) {
+    env.ledger().with_mut(|li| li.timestamp += secs);
+}
+
+// Commits PEG, then submits a 20% jump that trips the default breaker.
+fn trip(client: &PiPegOracleContractClient<'_>) {
+    client.update_peg(&PEG);
+    client.update_peg(&(PEG * 120 / 100));
+}
+
+#[test]
+fn test_breaker_trips_and_pauses() {
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+
+    assert_eq!(
+        client.try_update_peg(&0),
+        Err(Ok(PegOracleError::PegOutOfRange))
+    );
+
+    client.update_peg(&PEG);
+    assert_eq!(env.auths()[0].0, admin);
+
+    // Exactly max_change_bps still applies
+    client.update_peg(&(PEG * 110 / 100));
+    assert!(!client.is_paused());
+
+    client.update_peg(&(PEG * 2));
+    assert!(client.is_paused());
+    assert_eq!(client.pending_peg(), Some(PEG * 2));
+    assert_eq!(client.get_peg().value, PEG * 110 / 100);
+
+    assert_eq!(
+        client.try_update_peg(&PEG),
+        Err(Ok(PegOracleError::OraclePaused))
+    );
+    assert_eq!(
+        client.try_resume(&true),
+        Err(Ok(PegOracleError::GuardianNotSet))
+    );
+}
+
+#[test]
+fn test_guardian_resume() {
+    let env = Env::default();
+    let (client, _) = setup(&env);
+    let guardian = Address::generate(&env);
+
+    client.set_guardian(&guardian);
+    assert_eq!(client.guardian(), Some(guardian.clone()));
+
+    // Resuming without the pending update keeps the last peg
+    trip(&client);
+    client.resume(&false);
+    assert_eq!(env.auths()[0].0, guardian);
+    assert!(!client.is_paused());
+    assert_eq!(client.pending_peg(), None);
+    assert_eq!(client.get_peg().value, PEG);
+
+    // Resuming with it commits the held update
+    client.update_peg(&(PEG * 120 / 100));
+    assert!(client.is_paused());
+    client.resume(&true);
+    assert!(!client.is_paused());
+    assert_eq!(client.get_peg().value, PEG * 120 / 100);
+    assert_eq!(client.history().len(), 2);
+
+    client.update_peg(&(PEG * 125 / 100));
+    assert!(!client.is_paused());
+}
+
+#[test]
+fn test_breaker_settings_while_paused() {
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+    let guardian = Address::generate(&env);
+    let successor = Address::generate(&env);
+
+    client.set_guardian(&guardian);
+
+    // The sitting guardian has to agree to its replacement
+    client.set_guardian(&successor);
+    let auths = env.auths();
+    assert_eq!(auths.len(), 2);
+    assert_eq!(auths[0].0, admin);
+    assert_eq!(auths[1].0, guardian);
+    assert_eq!(client.guardian(), Some(successor.clone()));
+
+    trip(&client);
+    assert_eq!(
+        client.try_set_guardian(&Address::generate(&env)),
+        Err(Ok(PegOracleError::OraclePaused))
+    );
+    assert_eq!(
+        client.try_set_max_change_bps(&10_000),
+        Err(Ok(PegOracleError::OraclePaused))
+    );
+    assert_eq!(client.guardian(), Some(successor));
+    assert_eq!(client.max_change_bps(), DEFAULT_MAX_CHANGE_BPS);
+}
+
+#[test]
+fn test_breaker_limits_drift_per_window() {
+    let env = Env::default();
+    let (client, _) = setup(&env);
+    client.set_guardian(&Address::generate(&env));
+
+    // Two 6% steps: each within 10%, together 12.4% off the reference
+    client.update_peg(&PEG);
+    client.update_peg(&(PEG * 106 / 100));
+    advance(&env, CHANGE_WINDOW_SECS - 1);
+    client.update_peg(&(PEG * 1_124 / 1_000));
+    assert!(client.is_paused());
+    client.resume(&false);
+
+    // A new window measures from the peg it opens with
+    advance(&env, 1);
+    client.update_peg(&(PEG * 1_124 / 1_000));
+    assert!(!client.is_paused());
+    assert_eq!(client.get_peg().value, PEG * 1_124 / 1_000);
+}
+
+#[test]
+fn test_breaker_trips_on_overflow() {
+    let env = Env::default();
+    let (client, _) = setup(&env);
+
+    client.set_max_change_bps(&10_000);
+    client.update_peg(&1);
+    client.update_peg(&i128::MAX);
+    assert!(client.is_paused());
+    assert_eq!(client.pending_peg(), Some(i128::MAX));
+    assert_eq!(client.get_peg().value, 1);
+}
+
+#[test]
+fn test_max_change_bounds() {
+    let env = Env::default();
+    let (client, _) = setup(&env);
+
+    for bps in [0, 10_001] {
+        assert_eq!(
+            client.try_set_max_change_bps(&bps),
+            Err(Ok(PegOracleError::InvalidMaxChange))
+        );
+    }
+    assert_eq!(client.max_change_bps(), DEFAULT_MAX_CHANGE_BPS);
+
+    client.set_max_change_bps(&100);
+    client.update_peg(&PEG);
+    client.update_peg(&(PEG * 102 / 100));
+    assert!(client.is_paused());
+    assert_eq!(client.get_peg().value, PEG);
+}
+
+#[test]
+fn test_history_and_verify_peg() {
+    let env = Env::default();
+    let (client, _) = setup(&env);
+
+    assert_eq!(client.try_twap(&60), Err(Ok(PegOracleError::PegNotSet)));
+    assert_eq!(
+        client.try_verify_peg(&0),
+        Err(Ok(PegOracleError::PegNotSet))
+    );
+
+    client.update_peg(&(PEG + 10));
+    advance(&env, 100);
+    assert_eq!(client.history().len(), 1);
+    assert_eq!(client.get_peg_at(&START), PEG + 10);
+    assert_eq!(client.twap(&100), PEG + 10);
+    assert_eq!(
+        client.try_twap(&101),
+        Err(Ok(PegOracleError::InsufficientHistory))
+    );
+
+    assert!(client.verify_peg(&10));
+    assert!(!client.verify_peg(&9));
+
+    // A stale peg gets no verdict
+    advance(&env, DEFAULT_MAX_AGE_SECS);
+    assert_eq!(
+        client.try_verify_peg(&10),
+        Err(Ok(PegOracleError::PegStale))
+    );
+}
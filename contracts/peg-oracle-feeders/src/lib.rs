@@ -0,0 +1,687 @@
+#![no_std]
+
+use soroban_sdk::{
+    contract, contracterror, contractimpl, contracttype, symbol_short,
+    xdr::ToXdr, Env, Address, Symbol, Bytes, BytesN, Map, Vec,
+};
+use peg_history::HistoryError;
+
+//
+// =====================
+// ERROR
+// =====================
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum PegOracleError {
+    Unauthorized = 1,
+    /// Reserved: a bad feeder signature traps inside `ed25519_verify`.
+    InvalidSignature = 2,
+    PegExpired = 3,
+    PegNotSet = 4,
+    AlreadyInitialized = 5,
+    UnknownFeeder = 6,
+    FeederExists = 7,
+    InvalidQuorum = 8,
+    DuplicateSubmission = 9,
+    StaleTimestamp = 10,
+    TimestampInFuture = 11,
+    WrongRound = 12,
+    InvalidWindow = 13,
+    InsufficientHistory = 14,
+    PegStale = 15,
+    InvalidMaxAge = 16,
+    InvalidPegValue = 17,
+    InvalidNonce = 18,
+}
+
+const CONTRACT_VERSION: u32 = 1;
+
+// Submissions further than this from the round median are discarded.
+const MAX_FEEDER_DEVIATION_BPS: i128 = 500;
+
+// Observations older than MAX_PEG_AGE_SECS or more than MAX_FUTURE_SKEW_SECS
+// ahead of the ledger clock are rejected; pending submissions lapse once
+// they pass MAX_PEG_AGE_SECS.
+const MAX_PEG_AGE_SECS: u64 = 300;
+const MAX_FUTURE_SKEW_SECS: u64 = 30;
+
+// Prefix of every signed feeder payload.
+const PEG_DOMAIN: &[u8] = b"PI_PEG_ORACLE_V1";
+
+// Feeders submit pegs with six decimals, as in OFF-CHAIN FEEDER.
+const PEG_DECIMALS: u32 = 6;
+
+// Committed pegs older than this are reported as PegStale.
+const DEFAULT_MAX_AGE_SECS: u64 = 3_600;
+
+//
+// =====================
+// PEG DATA
+// =====================
+/// Latest committed round median with its freshness metadata.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PegData {
+    pub value: i128,
+    pub decimals: u32,
+    pub timestamp: u64,
+    pub round_id: u32,
+    pub source_count: u32,
+}
+
+/// `latestRoundData`-shaped view of the latest round.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RoundData {
+    pub round_id: u32,
+    pub answer: i128,
+    pub started_at: u64,
+    pub updated_at: u64,
+    pub answered_in_round: u32,
+}
+
+//
+// =====================
+// HISTORY
+// =====================
+pub use peg_history::Observation;
+
+impl From<HistoryError> for PegOracleError {
+    fn from(err: HistoryError) -> Self {
+        match err {
+            HistoryError::Empty => PegOracleError::PegNotSet,
+            HistoryError::InvalidWindow => PegOracleError::InvalidWindow,
+            HistoryError::InsufficientHistory => {
+                PegOracleError::InsufficientHistory
+            }
+        }
+    }
+}
+
+//
+// =====================
+// AGGREGATION
+// =====================
+fn sorted(env: &Env, values: &Vec<i128>) -> Vec<i128> {
+    let mut out: Vec<i128> = Vec::new(env);
+
+    for value in values.iter() {
+        let mut i = out.len();
+        while i > 0 && out.get_unchecked(i - 1) > value {
+            i -= 1;
+        }
+        out.insert(i, value);
+    }
+
+    out
+}
+
+fn median(env: &Env, values: &Vec<i128>) -> i128 {
+    let ordered = sorted(env, values);
+    let mid = ordered.len() / 2;
+
+    if ordered.len() % 2 == 1 {
+        ordered.get_unchecked(mid)
+    } else {
+        // Sorted, so the midpoint cannot overflow where a + b would.
+        let low = ordered.get_unchecked(mid - 1);
+        low + (ordered.get_unchecked(mid) - low) / 2
+    }
+}
+
+// A value too large to measure against the band is treated as outside it.
+fn within_band(value: i128, reference: i128) -> bool {
+    let diff = value.checked_sub(reference).and_then(i128::checked_abs);
+    let scaled = diff.and_then(|diff| diff.checked_mul(10_000));
+    let band = reference
+        .checked_abs()
+        .and_then(|reference| reference.checked_mul(MAX_FEEDER_DEVIATION_BPS));
+
+    match (scaled, band) {
+        (Some(scaled), Some(band)) => scaled <= band,
+        _ => false,
+    }
+}
+
+//
+// =====================
+// CONTRACT
+// =====================
+#[contract]
+pub struct PiPegOracleContract;
+
+#[contractimpl]
+impl PiPegOracleContract {
+
+    // -----------------
+    // INIT
+    // -----------------
+    pub fn initialize(
+        env: Env,
+        admin: Address,
+        feeders: Vec<BytesN<32>>, // ed25519 public keys
+        quorum: u32,
+    ) -> Result<(), PegOracleError> {
+        if env.storage().instance().has(&Symbol::new(&env, "admin")) {
+            return Err(PegOracleError::AlreadyInitialized);
+        }
+
+        admin.require_auth();
+
+        if quorum == 0 || quorum > feeders.len() {
+            return Err(PegOracleError::InvalidQuorum);
+        }
+
+        env.storage().instance().set(
+            &Symbol::new(&env, "admin"),
+            &admin,
+        );
+        env.storage().instance().set(
+            &Symbol::new(&env, "feeders"),
+            &feeders,
+        );
+        env.storage().instance().set(
+            &Symbol::new(&env, "quorum"),
+            &quorum,
+        );
+        env.storage().instance().set(
+            &Symbol::new(&env, "version"),
+            &CONTRACT_VERSION,
+        );
+
+        env.events().publish(
+            (symbol_short!("init"), admin),
+            (feeders, quorum),
+        );
+
+        Ok(())
+    }
+
+    // -----------------
+    // UPGRADE (ADMIN)
+    // -----------------
+    pub fn version(env: Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&Symbol::new(&env, "version"))
+            .unwrap_or(0)
+    }
+
+    pub fn upgrade(
+        env: Env,
+        new_wasm_hash: BytesN<32>,
+    ) -> Result<(), PegOracleError> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&Symbol::new(&env, "admin"))
+            .ok_or(PegOracleError::Unauthorized)?;
+
+        admin.require_auth();
+
+        env.deployer().update_current_contract_wasm(new_wasm_hash);
+
+        Ok(())
+    }
+
+    pub fn migrate(env: Env) -> Result<u32, PegOracleError> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&Symbol::new(&env, "admin"))
+            .ok_or(PegOracleError::Unauthorized)?;
+
+        admin.require_auth();
+
+        if Self::version(env.clone()) < CONTRACT_VERSION {
+            env.storage().instance().set(
+                &Symbol::new(&env, "version"),
+                &CONTRACT_VERSION,
+            );
+        }
+
+        Ok(CONTRACT_VERSION)
+    }
+
+    // -----------------
+    // FEEDER SET (ADMIN)
+    // -----------------
+    pub fn add_feeder(
+        env: Env,
+        feeder: BytesN<32>,
+    ) -> Result<(), PegOracleError> {
+        Self::require_admin(&env)?;
+
+        let mut feeders = Self::feeders(env.clone());
+        if feeders.contains(&feeder) {
+            return Err(PegOracleError::FeederExists);
+        }
+
+        feeders.push_back(feeder.clone());
+        env.storage().instance().set(
+            &Symbol::new(&env, "feeders"),
+            &feeders,
+        );
+
+        env.events().publish(
+            (symbol_short!("feeder"), symbol_short!("add")),
+            feeder,
+        );
+
+        Ok(())
+    }
+
+    pub fn remove_feeder(
+        env: Env,
+        feeder: BytesN<32>,
+    ) -> Result<(), PegOracleError> {
+        Self::require_admin(&env)?;
+
+        let mut feeders = Self::feeders(env.clone());
+        let index = feeders
+            .first_index_of(&feeder)
+            .ok_or(PegOracleError::UnknownFeeder)?;
+
+        if feeders.len() - 1 < Self::quorum(env.clone()) {
+            return Err(PegOracleError::InvalidQuorum);
+        }
+
+        feeders.remove(index);
+        env.storage().instance().set(
+            &Symbol::new(&env, "feeders"),
+            &feeders,
+        );
+
+        // A removed feeder's pending submission no longer counts.
+        let mut submissions = Self::submissions(env.clone());
+        submissions.remove(feeder.clone());
+        env.storage().instance().set(
+            &Symbol::new(&env, "subs"),
+            &submissions,
+        );
+
+        env.events().publish(
+            (symbol_short!("feeder"), symbol_short!("remove")),
+            feeder,
+        );
+
+        Self::reaggregate(&env, &submissions);
+
+        Ok(())
+    }
+
+    pub fn set_quorum(env: Env, quorum: u32) -> Result<(), PegOracleError> {
+        Self::require_admin(&env)?;
+
+        if quorum == 0 || quorum > Self::feeders(env.clone()).len() {
+            return Err(PegOracleError::InvalidQuorum);
+        }
+
+        env.storage().instance().set(
+            &Symbol::new(&env, "quorum"),
+            &quorum,
+        );
+
+        env.events().publish(
+            (symbol_short!("feeder"), symbol_short!("quorum")),
+            quorum,
+        );
+
+        // A lower quorum may already be met by the pending submissions.
+        Self::reaggregate(&env, &Self::submissions(env.clone()));
+
+        Ok(())
+    }
+
+    // -----------------
+    // COMMIT PEG (SIGNED, PER ROUND)
+    // -----------------
+    /// Records one feeder's signed observation for the current round. Once
+    /// `quorum` distinct feeders agree within the deviation band, the round
+    /// median is committed and the next round opens. Outliers and lapsed
+    /// submissions leave the round, so those feeders can submit again.
+    /// Each accepted submission consumes the feeder's `nonce`, so a signed
+    /// payload cannot be replayed by anyone who saw it.
+    pub fn commit_peg(
+        env: Env,
+        feeder: BytesN<32>,
+        round: u32,
+        nonce: u64,
+        peg_value: i128,
+        timestamp: u64,
+        signature: BytesN<64>,
+    ) -> Result<(), PegOracleError> {
+
+        if peg_value <= 0 {
+            return Err(PegOracleError::InvalidPegValue);
+        }
+
+        // Freshness check
+        let now = env.ledger().timestamp();
+        if now > timestamp + MAX_PEG_AGE_SECS {
+            return Err(PegOracleError::PegExpired);
+        }
+        if timestamp > now + MAX_FUTURE_SKEW_SECS {
+            return Err(PegOracleError::TimestampInFuture);
+        }
+
+        // Monotonic timestamps (anti replay)
+        if let Ok(last) = Self::last_update(env.clone()) {
+            if timestamp <= last {
+                return Err(PegOracleError::StaleTimestamp);
+            }
+        }
+
+        if round != Self::round(env.clone()) {
+            return Err(PegOracleError::WrongRound);
+        }
+
+        if !Self::feeders(env.clone()).contains(&feeder) {
+            return Err(PegOracleError::UnknownFeeder);
+        }
+
+        if nonce != Self::feeder_nonce(env.clone(), feeder.clone()) {
+            return Err(PegOracleError::InvalidNonce);
+        }
+
+        let hash =
+            Self::payload_hash(&env, round, nonce, peg_value, timestamp);
+
+        // Verify signature (traps on mismatch)
+        env.crypto().ed25519_verify(&feeder, &hash, &signature);
+
+        let mut submissions = Self::submissions(env.clone());
+        if submissions.contains_key(feeder.clone()) {
+            return Err(PegOracleError::DuplicateSubmission);
+        }
+
+        env.storage().instance().set(
+            &(symbol_short!("nonce"), feeder.clone()),
+            &(nonce + 1),
+        );
+
+        submissions.set(feeder, (peg_value, timestamp));
+        env.storage().instance().set(
+            &Symbol::new(&env, "subs"),
+            &submissions,
+        );
+
+        Self::reaggregate(&env, &submissions);
+
+        Ok(())
+    }
+
+    fn reaggregate(env: &Env, submissions: &Map<BytesN<32>, (i128, u64)>) {
+        if submissions.len() >= Self::quorum(env.clone()) {
+            Self::aggregate(env, submissions);
+        }
+    }
+
+    // Commits the round if enough submissions survive outlier rejection;
+    // otherwise the outliers are dropped and the round stays open.
+    fn aggregate(env: &Env, submissions: &Map<BytesN<32>, (i128, u64)>) {
+        let mut values = Vec::new(env);
+        for (value, _) in submissions.values().iter() {
+            values.push_back(value);
+        }
+        let reference = median(env, &values);
+
+        let mut accepted = Vec::new(env);
+        let mut kept = Map::new(env);
+        let mut oldest = u64::MAX;
+        for (feeder, (value, timestamp)) in submissions.iter() {
+            if within_band(value, reference) {
+                accepted.push_back(value);
+                kept.set(feeder, (value, timestamp));
+                oldest = oldest.min(timestamp);
+            } else {
+                env.events().publish(
+                    (symbol_short!("peg"), symbol_short!("outlier")),
+                    (feeder, value),
+                );
+            }
+        }
+
+        if accepted.len() < Self::quorum(env.clone()) {
+            env.storage().instance().set(&Symbol::new(env, "subs"), &kept);
+            return;
+        }
+
+        let peg_value = median(env, &accepted);
+        let round = Self::round(env.clone());
+
+        // Commit peg
+        env.storage().instance().set(
+            &Symbol::new(env, "peg"),
+            &peg_value,
+        );
+        env.storage().instance().set(
+            &Symbol::new(env, "peg_ts"),
+            &oldest,
+        );
+        peg_history::record(env, peg_value, oldest);
+        env.storage().instance().set(
+            &Symbol::new(env, "sources"),
+            &accepted.len(),
+        );
+        env.storage().instance().set(
+            &Symbol::new(env, "round"),
+            &(round + 1),
+        );
+        env.storage()
+            .instance()
+            .remove(&Symbol::new(env, "subs"));
+
+        env.events().publish(
+            (symbol_short!("peg"), symbol_short!("commit"), round),
+            (peg_value, oldest, accepted.len()),
+        );
+    }
+
+    /// sha256 of the domain-separated payload a feeder signs:
+    /// domain || contract address (ScVal XDR) || network id || round (u32 BE)
+    /// || nonce (u64 BE) || peg (i128 BE) || timestamp (u64 BE).
+    fn payload_hash(
+        env: &Env,
+        round: u32,
+        nonce: u64,
+        peg_value: i128,
+        timestamp: u64,
+    ) -> Bytes {
+        let mut payload = Bytes::from_slice(env, PEG_DOMAIN);
+        payload.append(&env.current_contract_address().to_xdr(env));
+        payload.extend_from_array(&env.ledger().network_id().to_array());
+        payload.extend_from_array(&round.to_be_bytes());
+        payload.extend_from_array(&nonce.to_be_bytes());
+        payload.extend_from_array(&peg_value.to_be_bytes());
+        payload.extend_from_array(&timestamp.to_be_bytes());
+
+        env.crypto().sha256(&payload).into()
+    }
+
+    fn require_admin(env: &Env) -> Result<Address, PegOracleError> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&Symbol::new(env, "admin"))
+            .ok_or(PegOracleError::Unauthorized)?;
+
+        admin.require_auth();
+
+        Ok(admin)
+    }
+
+    // -----------------
+    // READ PEG
+    // -----------------
+    /// Latest peg, or `PegStale` once it is older than `max_age`.
+    pub fn get_peg(env: Env) -> Result<PegData, PegOracleError> {
+        let data = Self::peg_data(&env)?;
+        let age = env.ledger().timestamp().saturating_sub(data.timestamp);
+
+        if age > Self::max_age(env.clone()) {
+            return Err(PegOracleError::PegStale);
+        }
+
+        Ok(data)
+    }
+
+    /// Latest round without a staleness check; consumers compare
+    /// `updated_at` against their own tolerance.
+    pub fn latest_round_data(env: Env) -> Result<RoundData, PegOracleError> {
+        let data = Self::peg_data(&env)?;
+
+        Ok(RoundData {
+            round_id: data.round_id,
+            answer: data.value,
+            started_at: data.timestamp,
+            updated_at: data.timestamp,
+            answered_in_round: data.round_id,
+        })
+    }
+
+    pub fn decimals(_env: Env) -> u32 {
+        PEG_DECIMALS
+    }
+
+    pub fn max_age(env: Env) -> u64 {
+        env.storage()
+            .instance()
+            .get(&Symbol::new(&env, "max_age"))
+            .unwrap_or(DEFAULT_MAX_AGE_SECS)
+    }
+
+    pub fn set_max_age(
+        env: Env,
+        max_age_secs: u64,
+    ) -> Result<(), PegOracleError> {
+        Self::require_admin(&env)?;
+
+        if max_age_secs == 0 {
+            return Err(PegOracleError::InvalidMaxAge);
+        }
+
+        env.storage().instance().set(
+            &Symbol::new(&env, "max_age"),
+            &max_age_secs,
+        );
+
+        env.events().publish(
+            (symbol_short!("peg"), symbol_short!("max_age")),
+            max_age_secs,
+        );
+
+        Ok(())
+    }
+
+    fn peg_data(env: &Env) -> Result<PegData, PegOracleError> {
+        let value: i128 = env
+            .storage()
+            .instance()
+            .get(&Symbol::new(env, "peg"))
+            .ok_or(PegOracleError::PegNotSet)?;
+        let timestamp: u64 = env
+            .storage()
+            .instance()
+            .get(&Symbol::new(env, "peg_ts"))
+            .ok_or(PegOracleError::PegNotSet)?;
+
+        Ok(PegData {
+            value,
+            decimals: PEG_DECIMALS,
+            timestamp,
+            round_id: Self::round(env.clone()).saturating_sub(1),
+            source_count: env
+                .storage()
+                .instance()
+                .get(&Symbol::new(env, "sources"))
+                .unwrap_or(0),
+        })
+    }
+
+    pub fn last_update(env: Env) -> Result<u64, PegOracleError> {
+        env.storage()
+            .instance()
+            .get(&Symbol::new(&env, "peg_ts"))
+            .ok_or(PegOracleError::PegNotSet)
+    }
+
+    // -----------------
+    // PEG HISTORY
+    // -----------------
+    pub fn history(env: Env) -> Vec<Observation> {
+        peg_history::observations(&env)
+    }
+
+    /// Peg in effect at `timestamp`: the latest observation not after it.
+    pub fn get_peg_at(
+        env: Env,
+        timestamp: u64,
+    ) -> Result<i128, PegOracleError> {
+        Ok(peg_history::peg_at(&env, timestamp)?)
+    }
+
+    /// Time-weighted average over the last `window_secs`, treating each
+    /// observation as the peg until the next one replaced it.
+    pub fn twap(env: Env, window_secs: u64) -> Result<i128, PegOracleError> {
+        Ok(peg_history::twap(&env, window_secs)?)
+    }
+
+    // -----------------
+    // FEEDER / ROUND VIEWS
+    // -----------------
+    pub fn feeders(env: Env) -> Vec<BytesN<32>> {
+        env.storage()
+            .instance()
+            .get(&Symbol::new(&env, "feeders"))
+            .unwrap_or(Vec::new(&env))
+    }
+
+    pub fn quorum(env: Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&Symbol::new(&env, "quorum"))
+            .unwrap_or(1)
+    }
+
+    /// Nonce the feeder's next signed payload must carry. It survives
+    /// removal, so re-adding a feeder does not revive old signatures.
+    pub fn feeder_nonce(env: Env, feeder: BytesN<32>) -> u64 {
+        env.storage()
+            .instance()
+            .get(&(symbol_short!("nonce"), feeder))
+            .unwrap_or(0)
+    }
+
+    /// Round currently collecting submissions; committed rounds are lower.
+    pub fn round(env: Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&Symbol::new(&env, "round"))
+            .unwrap_or(0)
+    }
+
+    /// Pending (peg, timestamp) submissions for the open round, without
+    /// those older than `MAX_PEG_AGE_SECS`.
+    pub fn submissions(env: Env) -> Map<BytesN<32>, (i128, u64)> {
+        let stored: Map<BytesN<32>, (i128, u64)> = env
+            .storage()
+            .instance()
+            .get(&Symbol::new(&env, "subs"))
+            .unwrap_or(Map::new(&env));
+        let now = env.ledger().timestamp();
+
+        let mut live = Map::new(&env);
+        for (feeder, (value, timestamp)) in stored.iter() {
+            if now <= timestamp + MAX_PEG_AGE_SECS {
+                live.set(feeder, (value, timestamp));
+            }
+        }
+
+        live
+    }
+}
+
+#[cfg(test)]
+mod test;
@@ -0,0 +1,320 @@
+#![cfg(test)]
+
+use super::*;
+use ed25519_dalek::{Signer, SigningKey};
+use soroban_sdk::testutils::{Address as _, Events as _, Ledger};
+
+const PEG: i128 = 314_159_000_000;
+const START: u64 = 10_000;
+
+fn signer(seed: u8) -> SigningKey {
+    SigningKey::from_bytes(&[seed; 32])
+}
+
+fn feeder(env: &Env, seed: u8) -> BytesN<32> {
+    BytesN::from_array(env, &signer(seed).verifying_key().to_bytes())
+}
+
+// Feeders use seeds 1..=count.
+fn setup(
+    env: &Env,
+    count: u8,
+    quorum: u32,
+) -> PiPegOracleContractClient<'_> {
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.timestamp = START);
+
+    let contract_id = env.register(PiPegOracleContract, ());
+    let client = PiPegOracleContractClient::new(env, &contract_id);
+
+    let mut feeders = Vec::new(env);
+    for seed in 1..=count {
+        feeders.push_back(feeder(env, seed));
+    }
+    client.initialize(&Address::generate(env), &feeders, &quorum);
+
+    client
+}
+
+fn sign(
+    env: &Env,
+    client: &PiPegOracleContractClient<'_>,
+    seed: u8,
+    nonce: u64,
+    peg_value: i128,
+) -> BytesN<64> {
+    let round = client.round();
+    let timestamp = env.ledger().timestamp();
+    let hash = env.as_contract(&client.address, || {
+        PiPegOracleContract::payload_hash(
+            env, round, nonce, peg_value, timestamp,
+        )
+    });
+
+    let mut message = [0u8; 32];
+    hash.copy_into_slice(&mut message);
+
+    BytesN::from_array(env, &signer(seed).sign(&message).to_bytes())
+}
+
+fn submit(
+    env: &Env,
+    client: &PiPegOracleContractClient<'_>,
+    seed: u8,
+    peg_value: i128,
+) -> Result<(), PegOracleError> {
+    let nonce = client.feeder_nonce(&feeder(env, seed));
+    let signature = sign(env, client, seed, nonce, peg_value);
+
+    match client.try_commit_peg(
+        &feeder(env, seed),
+        &client.round(),
+        &nonce,
+        &peg_value,
+        &env.ledger().timestamp(),
+        &signature,
+    ) {
+        Ok(Ok(())) => Ok(()),
+        Err(Ok(err)) => Err(err),
+        _ => panic!("unexpected host error"),
+    }
+}
+
+fn advance(env: &Env, secs: u64) {
+    env.ledger().with_mut(|li| li.timestamp += secs);
+}
+
+#[test]
+fn test_median_commits_at_quorum() {
+    let env = Env::default();
+    let client = setup(&env, 3, 3);
+
+    submit(&env, &client, 1, PEG).unwrap();
+    assert_eq!(
+        submit(&env, &client, 1, PEG),
+        Err(PegOracleError::DuplicateSubmission)
+    );
+    assert_eq!(
+        submit(&env, &client, 4, PEG),
+        Err(PegOracleError::UnknownFeeder)
+    );
+    assert_eq!(
+        submit(&env, &client, 2, 0),
+        Err(PegOracleError::InvalidPegValue)
+    );
+
+    submit(&env, &client, 2, PEG * 102 / 100).unwrap();
+    assert_eq!(client.try_get_peg(), Err(Ok(PegOracleError::PegNotSet)));
+
+    submit(&env, &client, 3, PEG * 101 / 100).unwrap();
+
+    let peg = client.get_peg();
+    assert_eq!(peg.value, PEG * 101 / 100);
+    assert_eq!(peg.timestamp, START);
+    assert_eq!(peg.round_id, 0);
+    assert_eq!(peg.source_count, 3);
+    assert_eq!(client.round(), 1);
+    assert!(client.submissions().is_empty());
+
+    // The committed round no longer takes submissions
+    advance(&env, 1);
+    let timestamp = env.ledger().timestamp();
+    assert_eq!(
+        client.try_commit_peg(
+            &feeder(&env, 1),
+            &0,
+            &1,
+            &PEG,
+            &timestamp,
+            &BytesN::from_array(&env, &[0; 64]),
+        ),
+        Err(Ok(PegOracleError::WrongRound))
+    );
+}
+
+#[test]
+fn test_outlier_can_resubmit() {
+    let env = Env::default();
+    let client = setup(&env, 3, 3);
+
+    submit(&env, &client, 1, PEG).unwrap();
+    submit(&env, &client, 2, PEG).unwrap();
+    submit(&env, &client, 3, PEG * 2).unwrap();
+    assert_eq!(env.events().all().events().len(), 1);
+
+    // Quorum needs all three feeders, so the outlier blocks the round
+    // until it resubmits within the band.
+    assert_eq!(client.round(), 0);
+    assert_eq!(client.submissions().len(), 2);
+    assert!(!client.submissions().contains_key(feeder(&env, 3)));
+
+    submit(&env, &client, 3, PEG * 101 / 100).unwrap();
+    assert_eq!(client.round(), 1);
+    assert_eq!(client.get_peg().value, PEG);
+}
+
+#[test]
+fn test_signed_payload_cannot_be_replayed() {
+    let env = Env::default();
+    let client = setup(&env, 3, 3);
+
+    submit(&env, &client, 1, PEG).unwrap();
+    submit(&env, &client, 2, PEG).unwrap();
+
+    // The outlier leaves the round, but its signature stays spent
+    let outlier = PEG * 2;
+    let signature = sign(&env, &client, 3, 0, outlier);
+    let timestamp = env.ledger().timestamp();
+    client.commit_peg(
+        &feeder(&env, 3),
+        &0,
+        &0,
+        &outlier,
+        &timestamp,
+        &signature,
+    );
+    assert_eq!(client.feeder_nonce(&feeder(&env, 3)), 1);
+    assert!(!client.submissions().contains_key(feeder(&env, 3)));
+
+    assert_eq!(
+        client.try_commit_peg(
+            &feeder(&env, 3),
+            &0,
+            &0,
+            &outlier,
+            &timestamp,
+            &signature,
+        ),
+        Err(Ok(PegOracleError::InvalidNonce))
+    );
+    assert_eq!(
+        client.try_commit_peg(
+            &feeder(&env, 3),
+            &0,
+            &2,
+            &outlier,
+            &timestamp,
+            &signature,
+        ),
+        Err(Ok(PegOracleError::InvalidNonce))
+    );
+    assert_eq!(client.round(), 0);
+
+    submit(&env, &client, 3, PEG).unwrap();
+    assert_eq!(client.round(), 1);
+}
+
+#[test]
+fn test_oversized_pegs_are_outliers() {
+    let env = Env::default();
+    let client = setup(&env, 2, 2);
+
+    // Neither the midpoint nor the band may overflow; both are dropped
+    submit(&env, &client, 1, i128::MAX).unwrap();
+    submit(&env, &client, 2, i128::MAX - 1).unwrap();
+    assert_eq!(env.events().all().events().len(), 2);
+    assert_eq!(client.round(), 0);
+    assert!(client.submissions().is_empty());
+
+    submit(&env, &client, 1, PEG).unwrap();
+    submit(&env, &client, 2, PEG + 1).unwrap();
+    assert_eq!(client.get_peg().value, PEG);
+}
+
+#[test]
+fn test_submissions_lapse() {
+    let env = Env::default();
+    let client = setup(&env, 2, 2);
+
+    submit(&env, &client, 1, PEG).unwrap();
+    advance(&env, MAX_PEG_AGE_SECS + 1);
+    assert!(client.submissions().is_empty());
+
+    // The lapsed feeder submits again and the round commits with the
+    // fresh timestamps only.
+    submit(&env, &client, 1, PEG).unwrap();
+    submit(&env, &client, 2, PEG).unwrap();
+
+    let peg = client.get_peg();
+    assert_eq!(peg.timestamp, START + MAX_PEG_AGE_SECS + 1);
+    assert_eq!(client.history().len(), 1);
+}
+
+#[test]
+fn test_quorum_change_reaggregates() {
+    let env = Env::default();
+    let client = setup(&env, 3, 3);
+
+    assert_eq!(
+        client.try_set_quorum(&4),
+        Err(Ok(PegOracleError::InvalidQuorum))
+    );
+    assert_eq!(
+        client.try_set_quorum(&0),
+        Err(Ok(PegOracleError::InvalidQuorum))
+    );
+
+    submit(&env, &client, 1, PEG).unwrap();
+    submit(&env, &client, 2, PEG * 101 / 100).unwrap();
+    assert_eq!(client.round(), 0);
+
+    client.set_quorum(&2);
+    assert_eq!(client.round(), 1);
+    assert_eq!(client.get_peg().value, PEG * 201 / 200);
+    assert_eq!(client.get_peg().source_count, 2);
+}
+
+#[test]
+fn test_feeder_management() {
+    let env = Env::default();
+    let client = setup(&env, 3, 2);
+
+    assert_eq!(
+        client.try_add_feeder(&feeder(&env, 1)),
+        Err(Ok(PegOracleError::FeederExists))
+    );
+    client.add_feeder(&feeder(&env, 4));
+    assert_eq!(client.feeders().len(), 4);
+
+    submit(&env, &client, 4, PEG).unwrap();
+    client.remove_feeder(&feeder(&env, 4));
+    assert!(client.submissions().is_empty());
+    assert_eq!(
+        submit(&env, &client, 4, PEG),
+        Err(PegOracleError::UnknownFeeder)
+    );
+    assert_eq!(
+        client.try_remove_feeder(&feeder(&env, 4)),
+        Err(Ok(PegOracleError::UnknownFeeder))
+    );
+
+    client.remove_feeder(&feeder(&env, 3));
+    assert_eq!(
+        client.try_remove_feeder(&feeder(&env, 2)),
+        Err(Ok(PegOracleError::InvalidQuorum))
+    );
+}
+
+#[test]
+fn test_history_records_commits() {
+    let env = Env::default();
+    let client = setup(&env, 1, 1);
+
+    assert_eq!(client.try_twap(&0), Err(Ok(PegOracleError::InvalidWindow)));
+    assert_eq!(
+        client.try_get_peg_at(&START),
+        Err(Ok(PegOracleError::PegNotSet))
+    );
+
+    submit(&env, &client, 1, PEG).unwrap();
+    advance(&env, 100);
+    assert_eq!(
+        client.history(),
+        Vec::from_array(&env, [Observation { value: PEG, timestamp: START }])
+    );
+    assert_eq!(client.twap(&100), PEG);
+    assert_eq!(
+        client.try_get_peg_at(&(START - 1)),
+        Err(Ok(PegOracleError::InsufficientHistory))
+    );
+}
@@ -1,8 +1,8 @@
 #![no_std]
 
 use soroban_sdk::{
-    contract, contractimpl, contracttype,
-    Env, Address, Symbol, Bytes, crypto,
+    contract, contracterror, contractimpl, contracttype, symbol_short,
+    Env, Address, Symbol, Bytes, BytesN,
 };
 
 //
@@ -28,14 +28,20 @@ pub struct PiCoinData {
     pub anti_fraud_hash: Bytes,
 }
 
-#[contracttype]
-#[derive(Clone, Debug, PartialEq)]
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
 pub enum PiCoinError {
-    InsufficientCollateral,
-    InvalidSource,
-    InvalidProvenance,
+    InsufficientCollateral = 1,
+    InvalidSource = 2,
+    InvalidProvenance = 3,
+    Unauthorized = 4,
+    AlreadyInitialized = 5,
 }
 
+// Schema version written at initialize and checked by migrate()
+pub const CONTRACT_VERSION: u32 = 1;
+
 //
 // =====================
 // CONTRACT
@@ -57,6 +63,12 @@ impl PiCoinContract {
         oracle: Address,
         governance: Address,
     ) -> Result<(), PiCoinError> {
+        if env.storage().instance().has(&Symbol::new(&env, "data")) {
+            return Err(PiCoinError::AlreadyInitialized);
+        }
+
+        admin.require_auth();
+
         let protocol_hash = env.crypto().sha256(
             &Bytes::from_slice(&env, b"PiCoin-Core-Protocol-Identifier"),
         );
@@ -64,7 +76,7 @@ impl PiCoinContract {
         let data = PiCoinData {
             total_supply: 100_000_000_000,
             peg_value: 314_159_000_000,
-            anti_fraud_hash: protocol_hash,
+            anti_fraud_hash: protocol_hash.into(),
         };
 
         env.storage()
@@ -74,18 +86,75 @@ impl PiCoinContract {
         env.storage()
             .instance()
             .set(&Symbol::new(&env, "admin"), &admin);
+        env.storage()
+            .instance()
+            .set(&Symbol::new(&env, "collateral"), &collateral);
+        env.storage()
+            .instance()
+            .set(&Symbol::new(&env, "oracle"), &oracle);
         env.storage()
             .instance()
             .set(&Symbol::new(&env, "governance"), &governance);
+        env.storage()
+            .instance()
+            .set(&Symbol::new(&env, "version"), &CONTRACT_VERSION);
 
-        env.logger().log(
-            &Symbol::new(&env, "INIT"),
-            "Protocol initialized with PQ-aware commitments",
+        env.events().publish(
+            (symbol_short!("init"), admin),
+            CONTRACT_VERSION,
         );
 
         Ok(())
     }
 
+    // -----------------
+    // UPGRADE (ADMIN ONLY)
+    // -----------------
+    pub fn version(env: Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&Symbol::new(&env, "version"))
+            .unwrap_or(0)
+    }
+
+    pub fn upgrade(
+        env: Env,
+        new_wasm_hash: BytesN<32>,
+    ) -> Result<(), PiCoinError> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&Symbol::new(&env, "admin"))
+            .ok_or(PiCoinError::Unauthorized)?;
+
+        admin.require_auth();
+
+        env.deployer().update_current_contract_wasm(new_wasm_hash);
+
+        Ok(())
+    }
+
+    // Post-upgrade hook: bring stored state up to CONTRACT_VERSION
+    pub fn migrate(env: Env) -> Result<u32, PiCoinError> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&Symbol::new(&env, "admin"))
+            .ok_or(PiCoinError::Unauthorized)?;
+
+        admin.require_auth();
+
+        let stored = Self::version(env.clone());
+
+        if stored < CONTRACT_VERSION {
+            env.storage()
+                .instance()
+                .set(&Symbol::new(&env, "version"), &CONTRACT_VERSION);
+        }
+
+        Ok(CONTRACT_VERSION)
+    }
+
     // -----------------
     // MINT (SOURCE-AWARE)
     // -----------------
@@ -109,9 +178,13 @@ impl PiCoinContract {
             .persistent()
             .set(&to, &source);
 
-        env.logger().log(
-            &Symbol::new(&env, "MINT"),
-            "Minted with valid source & PQ-aware provenance",
+        env.events().publish(
+            (symbol_short!("mint"), to.clone()),
+            amount,
+        );
+        env.events().publish(
+            (symbol_short!("prov"), to),
+            source,
         );
 
         Ok(())
@@ -140,9 +213,9 @@ impl PiCoinContract {
             return Err(PiCoinError::InvalidSource);
         }
 
-        env.logger().log(
-            &Symbol::new(&env, "TRANSFER"),
-            "Transfer passed ZKP gate and provenance validation",
+        env.events().publish(
+            (symbol_short!("transfer"), from, to),
+            amount,
         );
 
         Ok(())
@@ -159,11 +232,6 @@ impl PiCoinContract {
             return Err(PiCoinError::InvalidSource);
         }
 
-        env.logger().log(
-            &Symbol::new(&env, "PEG"),
-            "Peg verified via oracle commitment (PQ-ready)",
-        );
-
         Ok(())
     }
 
@@ -182,9 +250,9 @@ impl PiCoinContract {
             return Err(PiCoinError::InvalidSource);
         }
 
-        env.logger().log(
-            &proposal,
-            "DAO vote recorded with PQ-ready symbolic intent",
+        env.events().publish(
+            (symbol_short!("gov"), symbol_short!("vote"), voter),
+            proposal,
         );
 
         Ok(())
@@ -213,71 +281,50 @@ impl PiCoinContract {
 #[cfg(test)]
 mod test {
     use super::*;
-    use soroban_sdk::{Env, Address, Symbol, Bytes};
+    use soroban_sdk::testutils::Address as _;
 
     #[test]
     fn test_full_protocol_flow() {
         let env = Env::default();
         env.mock_all_auths();
 
-        let admin = Address::random(&env);
-        let user = Address::random(&env);
-        let receiver = Address::random(&env);
-        let collateral = Address::random(&env);
-        let oracle = Address::random(&env);
-        let governance = Address::random(&env);
+        let contract_id = env.register(PiCoinContract, ());
+        let client = PiCoinContractClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        let user = Address::generate(&env);
+        let receiver = Address::generate(&env);
+        let collateral = Address::generate(&env);
+        let oracle = Address::generate(&env);
+        let governance = Address::generate(&env);
 
         // Init
-        PiCoinContract::initialize(
-            env.clone(),
-            admin,
-            collateral,
-            oracle,
-            governance,
-        )
-        .unwrap();
+        client.initialize(&admin, &collateral, &oracle, &governance);
 
         // Mint
-        PiCoinContract::mint(
-            env.clone(),
-            user.clone(),
-            1_000_000,
-            PiCoinSource::Mining,
-        )
-        .unwrap();
+        client.mint(&user, &1_000_000, &PiCoinSource::Mining);
 
         // Setup ZKP base
-        let zkp_base = env.crypto().sha256(
-            &Bytes::from_slice(&env, b"zkp-seed"),
-        );
-        env.storage()
-            .instance()
-            .set(&Symbol::new(&env, "zkp_base"), &zkp_base);
+        let zkp_base: Bytes = env
+            .crypto()
+            .sha256(&Bytes::from_slice(&env, b"zkp-seed"))
+            .into();
+        env.as_contract(&contract_id, || {
+            env.storage()
+                .instance()
+                .set(&Symbol::new(&env, "zkp_base"), &zkp_base);
+        });
 
         // Transfer
-        PiCoinContract::transfer(
-            env.clone(),
-            user.clone(),
-            receiver,
-            500_000,
-        )
-        .unwrap();
+        client.transfer(&user, &receiver, &500_000);
 
         // Peg verify
-        PiCoinContract::verify_peg(env.clone(), user.clone()).unwrap();
+        client.verify_peg(&user);
 
         // Governance vote
-        PiCoinContract::governance_vote(
-            env.clone(),
-            user.clone(),
-            Symbol::new(&env, "rebase_policy"),
-        )
-        .unwrap();
+        client.governance_vote(&user, &Symbol::new(&env, "rebase_policy"));
 
         // Ecosystem entry
-        let ok =
-            PiCoinContract::verify_ecosystem_entry(env.clone(), user)
-                .unwrap();
-        assert!(ok);
+        assert!(client.verify_ecosystem_entry(&user));
     }
 }
@@ -0,0 +1,646 @@
+#![no_std]
+
+use soroban_sdk::{
+    contract, contracterror, contractimpl, contracttype, symbol_short,
+    Env, Address, Symbol, Bytes, BytesN, xdr::ToXdr,
+};
+
+/* =========================================================
+   ERROR DEFINITIONS
+   ========================================================= */
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum PiError {
+    InvalidSource = 1,
+    InvalidProvenance = 2,
+    InsufficientCollateral = 3,
+    MissingPQCommitment = 4,
+    Unauthorized = 5,
+    AlreadyInitialized = 6,
+    RoundNotFound = 7,
+    RoundExists = 8,
+    CommitPhaseClosed = 9,
+    RevealPhaseClosed = 10,
+    AlreadyRevealed = 11,
+    InvalidReveal = 12,
+    InvalidAmount = 13,
+    InvalidWindow = 14,
+}
+
+/* =========================================================
+   CORE TYPES
+   ========================================================= */
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub enum PiSource {
+    Mining,
+    Rewards,
+    P2P,
+    Invalid,
+}
+
+#[contracttype]
+#[derive(Clone)]
+pub struct PiState {
+    pub total_supply: i128,
+    pub peg_value: i128,
+    pub anti_fraud_hash: Bytes,
+}
+
+/* =========================================================
+   PQ COMMIT-REVEAL VOTING
+   ========================================================= */
+#[contracttype]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum PqChoice {
+    For,
+    Against,
+    Abstain,
+}
+
+/// Commits are accepted until `commit_end`, reveals until `reveal_end`.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct PqRound {
+    pub commit_end: u64,
+    pub reveal_end: u64,
+}
+
+/// Revealed votes, weighted by each voter's balance at commit time.
+#[contracttype]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct PqTally {
+    pub for_votes: i128,
+    pub against_votes: i128,
+    pub abstain_votes: i128,
+}
+
+#[contracttype]
+#[derive(Clone)]
+pub struct PqVoteKey {
+    pub proposal: Symbol,
+    pub voter: Address,
+}
+
+#[contracttype]
+#[derive(Clone)]
+pub enum PqKey {
+    Round(Symbol),
+    Tally(Symbol),
+    Commit(PqVoteKey),
+    Weight(PqVoteKey),
+    Revealed(PqVoteKey),
+}
+
+#[contracttype]
+#[derive(Clone)]
+pub enum AccountKey {
+    Balance(Address),
+}
+
+// Domain tag prefixed to every vote commitment preimage.
+const PQ_VOTE_DOMAIN: &[u8] = b"PI_DAO_PQ_VOTE_V1";
+
+/* =========================================================
+   VERSIONING
+   ========================================================= */
+pub const CONTRACT_VERSION: u32 = 1;
+
+/* =========================================================
+   STORAGE LAYER (INLINE)
+   ========================================================= */
+pub struct Store;
+
+impl Store {
+    // -------- CORE STATE --------
+    pub fn set_state(env: &Env, state: &PiState) {
+        env.storage().instance().set(&Symbol::new(env, "STATE"), state);
+    }
+
+    pub fn get_state(env: &Env) -> PiState {
+        env.storage().instance().get(&Symbol::new(env, "STATE")).unwrap()
+    }
+
+    pub fn has_state(env: &Env) -> bool {
+        env.storage().instance().has(&Symbol::new(env, "STATE"))
+    }
+
+    // -------- ADMIN & VERSION --------
+    pub fn set_admin(env: &Env, admin: &Address) {
+        env.storage().instance().set(&Symbol::new(env, "ADMIN"), admin);
+    }
+
+    pub fn get_admin(env: &Env) -> Result<Address, PiError> {
+        env.storage()
+            .instance()
+            .get(&Symbol::new(env, "ADMIN"))
+            .ok_or(PiError::Unauthorized)
+    }
+
+    pub fn set_version(env: &Env, version: u32) {
+        env.storage().instance().set(&Symbol::new(env, "VERSION"), &version);
+    }
+
+    pub fn get_version(env: &Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&Symbol::new(env, "VERSION"))
+            .unwrap_or(0)
+    }
+
+    // -------- PROVENANCE --------
+    pub fn set_provenance(env: &Env, addr: &Address, src: &PiSource) {
+        env.storage().persistent().set(addr, src);
+    }
+
+    pub fn get_provenance(env: &Env, addr: &Address) -> Result<PiSource, PiError> {
+        env.storage()
+            .persistent()
+            .get(addr)
+            .ok_or(PiError::InvalidProvenance)
+    }
+
+    // -------- BALANCES --------
+    pub fn set_balance(env: &Env, addr: &Address, amount: i128) {
+        env.storage()
+            .persistent()
+            .set(&AccountKey::Balance(addr.clone()), &amount);
+    }
+
+    pub fn get_balance(env: &Env, addr: &Address) -> i128 {
+        env.storage()
+            .persistent()
+            .get(&AccountKey::Balance(addr.clone()))
+            .unwrap_or(0)
+    }
+
+    // -------- PQ ROUNDS --------
+    pub fn set_pq_round(env: &Env, proposal: &Symbol, round: &PqRound) {
+        env.storage()
+            .persistent()
+            .set(&PqKey::Round(proposal.clone()), round);
+    }
+
+    pub fn get_pq_round(
+        env: &Env,
+        proposal: &Symbol,
+    ) -> Result<PqRound, PiError> {
+        env.storage()
+            .persistent()
+            .get(&PqKey::Round(proposal.clone()))
+            .ok_or(PiError::RoundNotFound)
+    }
+
+    pub fn set_pq_tally(env: &Env, proposal: &Symbol, tally: &PqTally) {
+        env.storage()
+            .persistent()
+            .set(&PqKey::Tally(proposal.clone()), tally);
+    }
+
+    pub fn get_pq_tally(env: &Env, proposal: &Symbol) -> PqTally {
+        env.storage()
+            .persistent()
+            .get(&PqKey::Tally(proposal.clone()))
+            .unwrap_or_default()
+    }
+
+    // -------- PQ COMMITMENTS --------
+    /// Stores the sealed ballot with the voting weight it will count for.
+    pub fn set_pq_commitment(
+        env: &Env,
+        key: &PqVoteKey,
+        hash: &BytesN<32>,
+        weight: i128,
+    ) {
+        let commit_key = PqKey::Commit(key.clone());
+
+        if !env.storage().persistent().has(&commit_key) {
+            let count = Self::pq_commitment_count(env);
+            env.storage()
+                .instance()
+                .set(&Symbol::new(env, "PQ_COUNT"), &(count + 1));
+        }
+
+        env.storage().persistent().set(&commit_key, hash);
+        env.storage()
+            .persistent()
+            .set(&PqKey::Weight(key.clone()), &weight);
+    }
+
+    pub fn get_pq_weight(env: &Env, key: &PqVoteKey) -> i128 {
+        env.storage()
+            .persistent()
+            .get(&PqKey::Weight(key.clone()))
+            .unwrap_or(0)
+    }
+
+    pub fn get_pq_commitment(
+        env: &Env,
+        key: &PqVoteKey,
+    ) -> Option<BytesN<32>> {
+        env.storage()
+            .persistent()
+            .get(&PqKey::Commit(key.clone()))
+    }
+
+    /// Number of voters who committed, once per proposal; re-commits that
+    /// replace a ballot are not counted again.
+    pub fn pq_commitment_count(env: &Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&Symbol::new(env, "PQ_COUNT"))
+            .unwrap_or(0)
+    }
+
+    pub fn set_revealed(env: &Env, key: &PqVoteKey) {
+        env.storage()
+            .persistent()
+            .set(&PqKey::Revealed(key.clone()), &true);
+    }
+
+    pub fn is_revealed(env: &Env, key: &PqVoteKey) -> bool {
+        env.storage()
+            .persistent()
+            .has(&PqKey::Revealed(key.clone()))
+    }
+}
+
+/* =========================================================
+   GOVERNANCE LOGIC (INLINE)
+   ========================================================= */
+pub struct Governance;
+
+impl Governance {
+    /// Opens a commit-reveal round for `proposal`
+    pub fn open_pq_round(
+        env: &Env,
+        proposal: Symbol,
+        commit_secs: u64,
+        reveal_secs: u64,
+    ) -> Result<PqRound, PiError> {
+        Store::get_admin(env)?.require_auth();
+
+        if Store::get_pq_round(env, &proposal).is_ok() {
+            return Err(PiError::RoundExists);
+        }
+
+        let commit_end = env
+            .ledger()
+            .timestamp()
+            .checked_add(commit_secs)
+            .ok_or(PiError::InvalidWindow)?;
+        let round = PqRound {
+            commit_end,
+            reveal_end: commit_end
+                .checked_add(reveal_secs)
+                .ok_or(PiError::InvalidWindow)?,
+        };
+
+        Store::set_pq_round(env, &proposal, &round);
+
+        env.events().publish(
+            (symbol_short!("gov"), symbol_short!("pq_round"), proposal),
+            (round.commit_end, round.reveal_end),
+        );
+        Ok(round)
+    }
+
+    /// Hash a voter commits to and later opens:
+    /// sha256(domain || voter || proposal || choice || sig_digest || salt),
+    /// where `sig_digest` is the digest of the voter's off-chain ML-DSA
+    /// signature over the ballot.
+    pub fn pq_vote_hash(
+        env: &Env,
+        voter: &Address,
+        proposal: &Symbol,
+        choice: PqChoice,
+        sig_digest: &BytesN<32>,
+        salt: &BytesN<32>,
+    ) -> BytesN<32> {
+        let choice_code: u32 = match choice {
+            PqChoice::For => 0,
+            PqChoice::Against => 1,
+            PqChoice::Abstain => 2,
+        };
+
+        let mut preimage = Bytes::from_slice(env, PQ_VOTE_DOMAIN);
+        preimage.append(&voter.clone().to_xdr(env));
+        preimage.append(&proposal.clone().to_xdr(env));
+        preimage.extend_from_array(&choice_code.to_be_bytes());
+        preimage.extend_from_array(&sig_digest.to_array());
+        preimage.extend_from_array(&salt.to_array());
+
+        env.crypto().sha256(&preimage).into()
+    }
+
+    /// Commit phase: store the voter's sealed ballot, weighted by the
+    /// voter's current balance. Re-committing before the phase closes
+    /// replaces the earlier commitment and its weight.
+    pub fn submit_pq_vote(
+        env: &Env,
+        voter: Address,
+        proposal: Symbol,
+        commitment: BytesN<32>,
+    ) -> Result<(), PiError> {
+        voter.require_auth();
+        Store::get_provenance(env, &voter)?;
+
+        let round = Store::get_pq_round(env, &proposal)?;
+        if env.ledger().timestamp() >= round.commit_end {
+            return Err(PiError::CommitPhaseClosed);
+        }
+
+        let key = PqVoteKey { proposal, voter: voter.clone() };
+        let weight = Store::get_balance(env, &voter);
+        Store::set_pq_commitment(env, &key, &commitment, weight);
+
+        env.events().publish(
+            (symbol_short!("gov"), symbol_short!("pq_commit"), voter),
+            (key.proposal, commitment),
+        );
+        Ok(())
+    }
+
+    /// Reveal phase: recompute the commitment on-chain and tally the vote
+    /// only if it matches.
+    pub fn reveal_pq_vote(
+        env: &Env,
+        voter: Address,
+        proposal: Symbol,
+        choice: PqChoice,
+        sig_digest: BytesN<32>,
+        salt: BytesN<32>,
+    ) -> Result<PqTally, PiError> {
+        voter.require_auth();
+
+        let round = Store::get_pq_round(env, &proposal)?;
+        let now = env.ledger().timestamp();
+        if now < round.commit_end || now >= round.reveal_end {
+            return Err(PiError::RevealPhaseClosed);
+        }
+
+        let key = PqVoteKey {
+            proposal: proposal.clone(),
+            voter: voter.clone(),
+        };
+
+        if Store::is_revealed(env, &key) {
+            return Err(PiError::AlreadyRevealed);
+        }
+
+        let stored = Store::get_pq_commitment(env, &key)
+            .ok_or(PiError::MissingPQCommitment)?;
+
+        let opened = Self::pq_vote_hash(
+            env, &voter, &proposal, choice, &sig_digest, &salt,
+        );
+        if opened != stored {
+            return Err(PiError::InvalidReveal);
+        }
+
+        let weight = Store::get_pq_weight(env, &key);
+        let mut tally = Store::get_pq_tally(env, &proposal);
+        match choice {
+            PqChoice::For => tally.for_votes += weight,
+            PqChoice::Against => tally.against_votes += weight,
+            PqChoice::Abstain => tally.abstain_votes += weight,
+        }
+
+        Store::set_revealed(env, &key);
+        Store::set_pq_tally(env, &proposal, &tally);
+
+        env.events().publish(
+            (symbol_short!("gov"), symbol_short!("pq_reveal"), voter),
+            (proposal, choice, weight),
+        );
+        Ok(tally)
+    }
+
+    /// Compare a voter's stored commitment against an expected hash
+    pub fn verify_pq_vote(
+        env: &Env,
+        voter: Address,
+        proposal: Symbol,
+        expected: BytesN<32>,
+    ) -> Result<bool, PiError> {
+        let key = PqVoteKey { proposal, voter };
+        let stored = Store::get_pq_commitment(env, &key)
+            .ok_or(PiError::MissingPQCommitment)?;
+
+        Ok(stored == expected)
+    }
+
+    /// Simple DAO vote gate (symbolic governance)
+    pub fn vote(
+        env: &Env,
+        voter: Address,
+        proposal: Symbol,
+    ) -> Result<(), PiError> {
+        Store::get_provenance(env, &voter)?;
+
+        env.events().publish(
+            (symbol_short!("gov"), symbol_short!("vote"), voter),
+            proposal,
+        );
+        Ok(())
+    }
+}
+
+/* =========================================================
+   CONTRACT ENTRY
+   ========================================================= */
+#[contract]
+pub struct PiCoinDAO;
+
+#[contractimpl]
+impl PiCoinDAO {
+    /* ---------- INITIALIZE ---------- */
+    pub fn initialize(env: Env, admin: Address) -> Result<(), PiError> {
+        if Store::has_state(&env) {
+            return Err(PiError::AlreadyInitialized);
+        }
+
+        admin.require_auth();
+
+        let hash = env.crypto().sha256(
+            &Bytes::from_slice(&env, b"PI_DAO_PQ_CORE_V1"),
+        );
+
+        let state = PiState {
+            total_supply: 100_000_000_000,
+            peg_value: 314_159_000_000,
+            anti_fraud_hash: hash.into(),
+        };
+
+        Store::set_state(&env, &state);
+        Store::set_admin(&env, &admin);
+        Store::set_version(&env, CONTRACT_VERSION);
+
+        env.events().publish(
+            (symbol_short!("init"), admin),
+            CONTRACT_VERSION,
+        );
+        Ok(())
+    }
+
+    /* ---------- VERSION & UPGRADE ---------- */
+    pub fn version(env: Env) -> u32 {
+        Store::get_version(&env)
+    }
+
+    pub fn upgrade(env: Env, new_wasm_hash: BytesN<32>) -> Result<(), PiError> {
+        Store::get_admin(&env)?.require_auth();
+
+        env.events().publish(
+            (symbol_short!("upgrade"),),
+            new_wasm_hash.clone(),
+        );
+
+        env.deployer().update_current_contract_wasm(new_wasm_hash);
+        Ok(())
+    }
+
+    /// Migration hook invoked by the admin once the new wasm is live
+    pub fn migrate(env: Env) -> Result<u32, PiError> {
+        Store::get_admin(&env)?.require_auth();
+
+        if Store::get_version(&env) < CONTRACT_VERSION {
+            Store::set_version(&env, CONTRACT_VERSION);
+        }
+        Ok(CONTRACT_VERSION)
+    }
+
+    /* ---------- MINT ---------- */
+    /// Admin only; the minted balance is the holder's voting weight.
+    pub fn mint(
+        env: Env,
+        to: Address,
+        amount: i128,
+        source: PiSource,
+    ) -> Result<(), PiError> {
+        Store::get_admin(&env)?.require_auth();
+
+        if source == PiSource::Invalid {
+            return Err(PiError::InvalidSource);
+        }
+
+        if amount <= 0 {
+            return Err(PiError::InvalidAmount);
+        }
+
+        if amount > 100_000_000 {
+            return Err(PiError::InsufficientCollateral);
+        }
+
+        let balance = Store::get_balance(&env, &to)
+            .checked_add(amount)
+            .ok_or(PiError::InvalidAmount)?;
+
+        Store::set_balance(&env, &to, balance);
+        Store::set_provenance(&env, &to, &source);
+
+        env.events().publish((symbol_short!("mint"), to.clone()), amount);
+        env.events().publish((symbol_short!("prov"), to), source);
+        Ok(())
+    }
+
+    pub fn balance(env: Env, addr: Address) -> i128 {
+        Store::get_balance(&env, &addr)
+    }
+
+    /* ---------- TRANSFER GATE ---------- */
+    pub fn transfer_gate(
+        env: Env,
+        from: Address,
+    ) -> Result<(), PiError> {
+        Store::get_provenance(&env, &from)?;
+
+        if Store::pq_commitment_count(&env) == 0 {
+            return Err(PiError::MissingPQCommitment);
+        }
+        Ok(())
+    }
+
+    /* ---------- DAO GOVERNANCE ---------- */
+    pub fn dao_vote(
+        env: Env,
+        voter: Address,
+        proposal: Symbol,
+    ) -> Result<(), PiError> {
+        Governance::vote(&env, voter, proposal)
+    }
+
+    /* ---------- PQ GOVERNANCE ---------- */
+    pub fn pq_open_round(
+        env: Env,
+        proposal: Symbol,
+        commit_secs: u64,
+        reveal_secs: u64,
+    ) -> Result<PqRound, PiError> {
+        Governance::open_pq_round(&env, proposal, commit_secs, reveal_secs)
+    }
+
+    pub fn pq_commit_vote(
+        env: Env,
+        voter: Address,
+        proposal: Symbol,
+        commitment: BytesN<32>,
+    ) -> Result<(), PiError> {
+        Governance::submit_pq_vote(&env, voter, proposal, commitment)
+    }
+
+    pub fn pq_reveal_vote(
+        env: Env,
+        voter: Address,
+        proposal: Symbol,
+        choice: PqChoice,
+        sig_digest: BytesN<32>,
+        salt: BytesN<32>,
+    ) -> Result<PqTally, PiError> {
+        Governance::reveal_pq_vote(
+            &env, voter, proposal, choice, sig_digest, salt,
+        )
+    }
+
+    pub fn pq_verify_vote(
+        env: Env,
+        voter: Address,
+        proposal: Symbol,
+        expected: BytesN<32>,
+    ) -> Result<bool, PiError> {
+        Governance::verify_pq_vote(&env, voter, proposal, expected)
+    }
+
+    pub fn pq_vote_hash(
+        env: Env,
+        voter: Address,
+        proposal: Symbol,
+        choice: PqChoice,
+        sig_digest: BytesN<32>,
+        salt: BytesN<32>,
+    ) -> BytesN<32> {
+        Governance::pq_vote_hash(
+            &env, &voter, &proposal, choice, &sig_digest, &salt,
+        )
+    }
+
+    pub fn pq_round(env: Env, proposal: Symbol) -> Result<PqRound, PiError> {
+        Store::get_pq_round(&env, &proposal)
+    }
+
+    pub fn pq_tally(env: Env, proposal: Symbol) -> PqTally {
+        Store::get_pq_tally(&env, &proposal)
+    }
+
+    /* ---------- ECOSYSTEM CHECK ---------- */
+    pub fn verify_ecosystem_entry(
+        env: Env,
+        user: Address,
+    ) -> bool {
+        Store::get_provenance(&env, &user).is_ok()
+    }
+}
+
+#[cfg(test)]
+mod test;
@@ -0,0 +1,257 @@
+#![cfg(test)]
+
+use super::*;
+use soroban_sdk::testutils::{Address as _, Ledger};
+
+const COMMIT_SECS: u64 = 100;
+const REVEAL_SECS: u64 = 50;
+
+fn setup(env: &Env) -> (PiCoinDAOClient<'_>, Symbol) {
+    env.mock_all_auths();
+
+    let contract_id = env.register(PiCoinDAO, ());
+    let client = PiCoinDAOClient::new(env, &contract_id);
+    let proposal = symbol_short!("prop1");
+
+    client.initialize(&Address::generate(env));
+    client.pq_open_round(&proposal, &COMMIT_SECS, &REVEAL_SECS);
+
+    (client, proposal)
+}
+
+fn voter(env: &Env, client: &PiCoinDAOClient<'_>) -> Address {
+    let voter = Address::generate(env);
+    client.mint(&voter, &1, &PiSource::Mining);
+    voter
+}
+
+fn seed(env: &Env, seed: u8) -> BytesN<32> {
+    BytesN::from_array(env, &[seed; 32])
+}
+
+fn commit(
+    env: &Env,
+    client: &PiCoinDAOClient<'_>,
+    voter: &Address,
+    proposal: &Symbol,
+    choice: PqChoice,
+) {
+    let hash = client.pq_vote_hash(
+        voter, proposal, &choice, &seed(env, 1), &seed(env, 2),
+    );
+    client.pq_commit_vote(voter, proposal, &hash);
+}
+
+fn reveal(
+    env: &Env,
+    client: &PiCoinDAOClient<'_>,
+    voter: &Address,
+    proposal: &Symbol,
+    choice: PqChoice,
+) -> Result<PqTally, PiError> {
+    match client.try_pq_reveal_vote(
+        voter, proposal, &choice, &seed(env, 1), &seed(env, 2),
+    ) {
+        Ok(Ok(tally)) => Ok(tally),
+        Err(Ok(err)) => Err(err),
+        _ => panic!("unexpected host error"),
+    }
+}
+
+fn advance(env: &Env, secs: u64) {
+    env.ledger().with_mut(|li| li.timestamp += secs);
+}
+
+#[test]
+fn test_commit_reveal_tally() {
+    let env = Env::default();
+    let (client, proposal) = setup(&env);
+    let alice = voter(&env, &client);
+    let bob = voter(&env, &client);
+
+    assert_eq!(
+        client.try_pq_open_round(&proposal, &1, &1),
+        Err(Ok(PiError::RoundExists))
+    );
+
+    commit(&env, &client, &alice, &proposal, PqChoice::For);
+    commit(&env, &client, &bob, &proposal, PqChoice::Against);
+
+    // Reveals only open once the commit phase has closed
+    assert_eq!(
+        reveal(&env, &client, &alice, &proposal, PqChoice::For),
+        Err(PiError::RevealPhaseClosed)
+    );
+
+    advance(&env, COMMIT_SECS);
+    let tally = reveal(&env, &client, &alice, &proposal, PqChoice::For);
+    assert_eq!(tally.unwrap().for_votes, 1);
+    reveal(&env, &client, &bob, &proposal, PqChoice::Against).unwrap();
+
+    assert_eq!(
+        client.pq_tally(&proposal),
+        PqTally { for_votes: 1, against_votes: 1, abstain_votes: 0 }
+    );
+}
+
+#[test]
+fn test_phase_windows() {
+    let env = Env::default();
+    let (client, proposal) = setup(&env);
+    let alice = voter(&env, &client);
+    let bob = voter(&env, &client);
+    let hash = seed(&env, 3);
+
+    assert_eq!(
+        client.try_pq_commit_vote(&alice, &symbol_short!("none"), &hash),
+        Err(Ok(PiError::RoundNotFound))
+    );
+    let outsider = Address::generate(&env);
+    assert_eq!(
+        client.try_pq_commit_vote(&outsider, &proposal, &hash),
+        Err(Ok(PiError::InvalidProvenance))
+    );
+
+    commit(&env, &client, &alice, &proposal, PqChoice::For);
+    commit(&env, &client, &bob, &proposal, PqChoice::For);
+
+    advance(&env, COMMIT_SECS);
+    assert_eq!(
+        client.try_pq_commit_vote(&alice, &proposal, &hash),
+        Err(Ok(PiError::CommitPhaseClosed))
+    );
+    reveal(&env, &client, &alice, &proposal, PqChoice::For).unwrap();
+
+    advance(&env, REVEAL_SECS);
+    assert_eq!(
+        reveal(&env, &client, &bob, &proposal, PqChoice::For),
+        Err(PiError::RevealPhaseClosed)
+    );
+    assert_eq!(client.pq_tally(&proposal).for_votes, 1);
+}
+
+#[test]
+fn test_reveal_errors() {
+    let env = Env::default();
+    let (client, proposal) = setup(&env);
+    let alice = voter(&env, &client);
+    let bob = voter(&env, &client);
+
+    commit(&env, &client, &alice, &proposal, PqChoice::For);
+    advance(&env, COMMIT_SECS);
+
+    assert_eq!(
+        reveal(&env, &client, &alice, &proposal, PqChoice::Against),
+        Err(PiError::InvalidReveal)
+    );
+    assert_eq!(
+        reveal(&env, &client, &bob, &proposal, PqChoice::For),
+        Err(PiError::MissingPQCommitment)
+    );
+
+    reveal(&env, &client, &alice, &proposal, PqChoice::For).unwrap();
+    assert_eq!(
+        reveal(&env, &client, &alice, &proposal, PqChoice::For),
+        Err(PiError::AlreadyRevealed)
+    );
+    assert_eq!(client.pq_tally(&proposal).for_votes, 1);
+}
+
+#[test]
+fn test_votes_weighted_by_balance() {
+    let env = Env::default();
+    let (client, proposal) = setup(&env);
+    let alice = voter(&env, &client);
+    let bob = voter(&env, &client);
+
+    assert_eq!(
+        client.try_mint(&alice, &0, &PiSource::Mining),
+        Err(Ok(PiError::InvalidAmount))
+    );
+    client.mint(&alice, &4, &PiSource::Rewards);
+    assert_eq!(client.balance(&alice), 5);
+
+    commit(&env, &client, &alice, &proposal, PqChoice::For);
+    commit(&env, &client, &bob, &proposal, PqChoice::Against);
+
+    // Weight is fixed at commit time
+    client.mint(&bob, &100, &PiSource::Mining);
+
+    advance(&env, COMMIT_SECS);
+    reveal(&env, &client, &alice, &proposal, PqChoice::For).unwrap();
+    reveal(&env, &client, &bob, &proposal, PqChoice::Against).unwrap();
+    assert_eq!(
+        client.pq_tally(&proposal),
+        PqTally { for_votes: 5, against_votes: 1, abstain_votes: 0 }
+    );
+}
+
+#[test]
+fn test_admin_gates() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(PiCoinDAO, ());
+    let client = PiCoinDAOClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+
+    let alice = Address::generate(&env);
+    assert_eq!(
+        client.try_mint(&alice, &1, &PiSource::Mining),
+        Err(Ok(PiError::Unauthorized))
+    );
+
+    client.initialize(&admin);
+    client.mint(&alice, &1, &PiSource::Mining);
+    assert_eq!(env.auths()[0].0, admin);
+
+    assert_eq!(
+        client.try_pq_open_round(&symbol_short!("late"), &u64::MAX, &1),
+        Err(Ok(PiError::InvalidWindow))
+    );
+    env.ledger().with_mut(|li| li.timestamp = 1);
+    assert_eq!(
+        client.try_pq_open_round(&symbol_short!("late"), &1, &u64::MAX),
+        Err(Ok(PiError::InvalidWindow))
+    );
+}
+
+#[test]
+fn test_recommit_replaces_ballot() {
+    let env = Env::default();
+    let (client, proposal) = setup(&env);
+    let alice = voter(&env, &client);
+
+    assert_eq!(
+        client.try_transfer_gate(&alice),
+        Err(Ok(PiError::MissingPQCommitment))
+    );
+
+    commit(&env, &client, &alice, &proposal, PqChoice::For);
+    commit(&env, &client, &alice, &proposal, PqChoice::Abstain);
+    assert_eq!(
+        env.as_contract(&client.address, || {
+            Store::pq_commitment_count(&env)
+        }),
+        1
+    );
+    client.transfer_gate(&alice);
+
+    let abstain = client.pq_vote_hash(
+        &alice,
+        &proposal,
+        &PqChoice::Abstain,
+        &seed(&env, 1),
+        &seed(&env, 2),
+    );
+    assert!(client.pq_verify_vote(&alice, &proposal, &abstain));
+
+    advance(&env, COMMIT_SECS);
+    assert_eq!(
+        reveal(&env, &client, &alice, &proposal, PqChoice::For),
+        Err(PiError::InvalidReveal)
+    );
+    let tally =
+        reveal(&env, &client, &alice, &proposal, PqChoice::Abstain);
+    assert_eq!(tally.unwrap().abstain_votes, 1);
+}
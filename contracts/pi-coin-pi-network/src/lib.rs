@@ -0,0 +1,1167 @@
+#![no_std]
+
+use soroban_sdk::{
+    contract, contractclient, contracterror, contractimpl, contracttype,
+    symbol_short, xdr::ToXdr, Env, Address, Bytes, BytesN,
+};
+
+/* ============================================================
+   CONTRACT VERSION
+   ============================================================ */
+
+pub const CONTRACT_VERSION: u32 = 1;
+
+/* ============================================================
+   ERRORS
+   ============================================================ */
+
+/// Numeric codes are part of the public API (see docs/errors.md): never
+/// renumber a variant, only append new ones.
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum PiCoinError {
+    InvalidQuantumSignature = 1,
+    NonceReused = 2,
+    InvalidNonce = 3,
+    InsufficientBalance = 4,
+    AlreadyInitialized = 5,
+    NotInitialized = 6,
+    QuantumKeyActive = 7,
+    NoActiveQuantumKey = 8,
+    RotationNotEndorsed = 9,
+    InvalidAmount = 10,
+    MintCapExceeded = 11,
+    InvalidMarketPrice = 12,
+    ParameterOutOfBounds = 13,
+    ActionNotQueued = 14,
+    TimelockNotExpired = 15,
+    GuardianNotSet = 16,
+    TimelockDelayTooShort = 17,
+    RebaseEpochNotElapsed = 18,
+    ReregistrationTooSoon = 19,
+    OracleNotSet = 20,
+    OracleUnavailable = 21,
+    InvalidPegValue = 22,
+    OracleStale = 23,
+}
+
+/* ============================================================
+   TIMELOCK
+   ============================================================ */
+
+pub const DEFAULT_TIMELOCK_DELAY: u64 = 2 * 24 * 60 * 60;
+// Floor for SetTimelockDelay so the admin cannot skip the guardian window.
+pub const MIN_TIMELOCK_DELAY: u64 = 60 * 60;
+
+/* ============================================================
+   REBASE
+   ============================================================ */
+
+// Fixed-point scale of the rebase index: 1_000_000_000 = 1.0
+pub const INDEX_SCALE: i128 = 1_000_000_000;
+
+// At most one rebase per epoch, moving the index by at most MAX_REBASE_BPS.
+pub const REBASE_EPOCH_SECS: u64 = 24 * 60 * 60;
+pub const MAX_REBASE_BPS: i128 = 500;
+
+pub const DEFAULT_PEG_VALUE: i128 = 314_159_000_000;
+
+// Decimals of `peg_value`, and of the oracle answers rebases are fed from.
+pub const PEG_DECIMALS: u32 = 6;
+
+/* ============================================================
+   PARAMETERS
+   ============================================================ */
+
+pub const DEFAULT_MINT_CAP: i128 = 100_000_000_000;
+pub const DEFAULT_COLLATERAL_RATIO_BPS: u32 = 15_000;
+pub const DEFAULT_PEG_TOLERANCE_BPS: u32 = 100;
+pub const DEFAULT_ORACLE_MAX_AGE_SECS: u64 = 60 * 60;
+
+pub const MIN_COLLATERAL_RATIO_BPS: u32 = 10_000;
+pub const MAX_COLLATERAL_RATIO_BPS: u32 = 50_000;
+pub const MAX_PEG_TOLERANCE_BPS: u32 = 1_000;
+pub const MIN_ORACLE_MAX_AGE_SECS: u64 = 60;
+pub const MAX_ORACLE_MAX_AGE_SECS: u64 = 24 * 60 * 60;
+pub const MAX_FEE_BPS: u32 = 1_000;
+
+/* ============================================================
+   PI NETWORK COMPILE GUARD
+   ============================================================ */
+
+#[cfg(all(feature = "pi_network", feature = "dao_native"))]
+compile_error!("Pi Network does NOT support native DAO governance");
+
+/* ============================================================
+   NETWORK METADATA (STATIC)
+   ============================================================ */
+
+#[cfg(feature = "pi_network")]
+mod pi_network {
+    pub const NAME: &str = "Pi Network";
+    pub const CONSENSUS: &str = "SCP";
+    pub const GOVERNANCE: &str = "Core-Team-Led";
+}
+
+/* ============================================================
+   GOVERNANCE MODEL (PI-COMPATIBLE)
+   ============================================================ */
+
+#[contracttype]
+#[derive(Clone)]
+pub enum GovernanceMode {
+    CoreTeamLed,
+}
+
+/// Balances are stored as shares; an account holds
+/// `shares * rebase_index / INDEX_SCALE` PI.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct PiCoinData {
+    pub peg_value: i128,
+    pub rebase_index: i128,
+    /// Ledger timestamp of the last rebase; `None` until the first one.
+    pub last_rebase: Option<u64>,
+}
+
+/// Protocol parameters governance can change through `ParameterUpdate`.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct Parameters {
+    pub mint_cap: i128,
+    pub collateral_ratio_bps: u32,
+    pub peg_tolerance_bps: u32,
+    pub oracle_max_age_secs: u64,
+    pub fee_bps: u32,
+}
+
+/// A single typed parameter change; see `check_parameter` for bounds.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub enum Parameter {
+    MintCap(i128),
+    /// Published for the collateral vault; 100%..=500%.
+    CollateralRatio(u32),
+    PegTolerance(u32),
+    OracleStaleness(u64),
+    /// Share of each transfer burned before it reaches the recipient, in
+    /// bps of the amount; at most `MAX_FEE_BPS`.
+    FeeBps(u32),
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub enum ProposalAction {
+    /// Observed market price, in the same units as `PiCoinData.peg_value`.
+    Rebase(i128),
+    ParameterUpdate(Parameter),
+    RotateOracle(Address),
+    /// New `PiCoinData.peg_value` that rebases steer towards.
+    UpdatePeg(i128),
+    /// Guardian and delay changes wait out the current delay too, so the
+    /// sitting guardian can cancel an attempt to replace it.
+    SetGuardian(Address),
+    SetTimelockDelay(u64),
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct QueuedAction {
+    pub action: ProposalAction,
+    pub eta: u64,
+}
+
+/* ============================================================
+   PEG ORACLE
+   ============================================================ */
+
+/// Mirrors `RoundData` from `PiPegOracleContract::latest_round_data`.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct RoundData {
+    pub round_id: u32,
+    pub answer: i128,
+    pub started_at: u64,
+    pub updated_at: u64,
+    pub answered_in_round: u32,
+}
+
+#[allow(dead_code)]
+#[contractclient(name = "PegOracleClient")]
+pub trait PegOracleInterface {
+    fn decimals(env: Env) -> u32;
+    fn latest_round_data(env: Env) -> RoundData;
+}
+
+fn read_oracle_round(env: &Env) -> Result<RoundData, PiCoinError> {
+    let oracle: Address = env
+        .storage()
+        .instance()
+        .get(&DataKey::Oracle)
+        .ok_or(PiCoinError::OracleNotSet)?;
+    let client = PegOracleClient::new(env, &oracle);
+
+    match client.try_decimals() {
+        Ok(Ok(PEG_DECIMALS)) => {}
+        _ => return Err(PiCoinError::OracleUnavailable),
+    }
+
+    match client.try_latest_round_data() {
+        Ok(Ok(round)) if round.answer > 0 => Ok(round),
+        _ => Err(PiCoinError::OracleUnavailable),
+    }
+}
+
+/* ============================================================
+   POST-QUANTUM KEY REGISTRY
+   ============================================================ */
+
+#[contracttype]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum PqAlgorithm {
+    MlDsa44,
+    MlDsa65,
+    MlDsa87,
+    SlhDsa,
+}
+
+/// One entry in an owner's key history. Keys are appended in order and
+/// never deleted; a key is active from `activated_at` until `revoked_at`.
+/// `rotation_commitment` is `rotation_commitment(secret)` for a secret only
+/// the key holder knows; revealing it is what authorizes the next key.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct QuantumKeyRecord {
+    pub algorithm: PqAlgorithm,
+    pub key_hash: BytesN<32>,
+    pub activated_at: u32,
+    pub revoked_at: Option<u32>,
+    pub rotation_commitment: BytesN<32>,
+}
+
+#[contracttype]
+#[derive(Clone)]
+pub struct QuantumKeyId {
+    pub owner: Address,
+    pub index: u32,
+}
+
+// Domain tags for the transfer digest and rotation commitments.
+const PQ_ROTATE_DOMAIN: &[u8] = b"PI_PQ_ROTATE_V1";
+
+// Ledgers (~1 day) an owner must wait after revoking before registering a
+// fresh key, so revoke + register cannot stand in for a secret-gated rotation.
+pub const PQ_REREGISTER_DELAY_LEDGERS: u32 = 17_280;
+const PQ_TRANSFER_DOMAIN: &[u8] = b"PI_PQ_TRANSFER_V1";
+
+/* ============================================================
+   STORAGE KEYS
+   ============================================================ */
+
+#[contracttype]
+pub enum DataKey {
+    Admin,
+    Governance,
+    Version,
+    QuantumKey(QuantumKeyId),
+    QuantumKeyCount(Address),
+    Nonce(Address),
+    Balance(Address),
+    Guardian,
+    TimelockDelay,
+    ActionCount,
+    QueuedAction(u32),
+    Data,
+    TotalShares,
+    Parameters,
+    Oracle,
+}
+
+/* ============================================================
+   QUANTUM-SAFE SYMBOLIC SIGNATURE
+   (Dilithium off-chain → hash on-chain)
+   ============================================================ */
+
+#[contracttype]
+#[derive(Clone)]
+pub struct QuantumSignature {
+    pub hash: BytesN<32>,
+}
+
+/// Digest a transfer signature must match. Binding the active key, both
+/// parties, the amount, the sender's nonce, this contract and the network
+/// makes every signature single-use and non-portable.
+fn transfer_digest(
+    env: &Env,
+    key_hash: &BytesN<32>,
+    from: &Address,
+    to: &Address,
+    amount: i128,
+    nonce: u64,
+) -> BytesN<32> {
+    let mut preimage = Bytes::from_slice(env, PQ_TRANSFER_DOMAIN);
+    preimage.extend_from_array(&key_hash.to_array());
+    preimage.append(&from.clone().to_xdr(env));
+    preimage.append(&to.clone().to_xdr(env));
+    preimage.extend_from_array(&amount.to_be_bytes());
+    preimage.extend_from_array(&nonce.to_be_bytes());
+    preimage.append(&env.current_contract_address().to_xdr(env));
+    preimage.extend_from_array(&env.ledger().network_id().to_array());
+
+    env.crypto().sha256(&preimage).into()
+}
+
+fn read_nonce(env: &Env, owner: &Address) -> u64 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Nonce(owner.clone()))
+        .unwrap_or(0)
+}
+
+/// Checks a transfer signature and consumes the sender's nonce.
+fn verify_quantum_signature(
+    env: &Env,
+    from: &Address,
+    to: &Address,
+    amount: i128,
+    nonce: u64,
+    sig: &QuantumSignature,
+) -> Result<(), PiCoinError> {
+    let expected = read_nonce(env, from);
+    if nonce < expected {
+        return Err(PiCoinError::NonceReused);
+    }
+    if nonce > expected {
+        return Err(PiCoinError::InvalidNonce);
+    }
+
+    let (_, key) = active_quantum_key(env, from)
+        .ok_or(PiCoinError::InvalidQuantumSignature)?;
+
+    if sig.hash != transfer_digest(env, &key.key_hash, from, to, amount, nonce)
+    {
+        return Err(PiCoinError::InvalidQuantumSignature);
+    }
+
+    env.storage()
+        .persistent()
+        .set(&DataKey::Nonce(from.clone()), &(nonce + 1));
+
+    Ok(())
+}
+
+fn quantum_key_count(env: &Env, owner: &Address) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::QuantumKeyCount(owner.clone()))
+        .unwrap_or(0)
+}
+
+fn read_quantum_key(
+    env: &Env,
+    owner: &Address,
+    index: u32,
+) -> Option<QuantumKeyRecord> {
+    env.storage().persistent().get(&DataKey::QuantumKey(QuantumKeyId {
+        owner: owner.clone(),
+        index,
+    }))
+}
+
+fn write_quantum_key(
+    env: &Env,
+    owner: &Address,
+    index: u32,
+    record: &QuantumKeyRecord,
+) {
+    env.storage().persistent().set(
+        &DataKey::QuantumKey(QuantumKeyId {
+            owner: owner.clone(),
+            index,
+        }),
+        record,
+    );
+}
+
+// Only the newest key can be active; older ones are revoked on rotation.
+fn active_quantum_key(
+    env: &Env,
+    owner: &Address,
+) -> Option<(u32, QuantumKeyRecord)> {
+    let count = quantum_key_count(env, owner);
+    if count == 0 {
+        return None;
+    }
+
+    read_quantum_key(env, owner, count - 1)
+        .filter(|record| record.revoked_at.is_none())
+        .map(|record| (count - 1, record))
+}
+
+fn append_quantum_key(
+    env: &Env,
+    owner: &Address,
+    algorithm: PqAlgorithm,
+    key_hash: &BytesN<32>,
+    rotation_commitment: &BytesN<32>,
+) -> u32 {
+    let index = quantum_key_count(env, owner);
+
+    write_quantum_key(
+        env,
+        owner,
+        index,
+        &QuantumKeyRecord {
+            algorithm,
+            key_hash: key_hash.clone(),
+            activated_at: env.ledger().sequence(),
+            revoked_at: None,
+            rotation_commitment: rotation_commitment.clone(),
+        },
+    );
+    env.storage()
+        .persistent()
+        .set(&DataKey::QuantumKeyCount(owner.clone()), &(index + 1));
+
+    index
+}
+
+/// sha256(domain || secret). Each key is registered with the commitment
+/// to a fresh secret, derived off-chain from the PQ private key, and the
+/// secret is revealed to rotate away from it: a hash chain that the owner
+/// account's classical signature alone cannot extend.
+fn rotation_commitment(env: &Env, secret: &BytesN<32>) -> BytesN<32> {
+    let mut preimage = Bytes::from_slice(env, PQ_ROTATE_DOMAIN);
+    preimage.extend_from_array(&secret.to_array());
+
+    env.crypto().sha256(&preimage).into()
+}
+
+/* ============================================================
+   ACCESS CONTROL
+   ============================================================ */
+
+fn require_admin(env: &Env) -> Result<Address, PiCoinError> {
+    let admin: Address = env
+        .storage()
+        .instance()
+        .get(&DataKey::Admin)
+        .ok_or(PiCoinError::NotInitialized)?;
+
+    admin.require_auth();
+
+    Ok(admin)
+}
+
+/* ============================================================
+   SHARE ACCOUNTING
+   ============================================================ */
+
+fn read_data(env: &Env) -> PiCoinData {
+    env.storage()
+        .instance()
+        .get(&DataKey::Data)
+        .unwrap_or(PiCoinData {
+            peg_value: DEFAULT_PEG_VALUE,
+            rebase_index: INDEX_SCALE,
+            last_rebase: None,
+        })
+}
+
+fn to_amount(shares: i128, index: i128) -> i128 {
+    shares * index / INDEX_SCALE
+}
+
+// Rounds up so a transfer never moves more value than it debits.
+fn to_shares(amount: i128, index: i128) -> Result<i128, PiCoinError> {
+    amount
+        .checked_mul(INDEX_SCALE)
+        .and_then(|scaled| scaled.checked_add(index - 1))
+        .map(|scaled| scaled / index)
+        .ok_or(PiCoinError::InvalidAmount)
+}
+
+/// Moves the rebase index towards the peg: a market price above
+/// `peg_value` expands supply, below it contracts supply.
+fn apply_rebase(env: &Env, market_price: i128) -> Result<(), PiCoinError> {
+    let mut data = read_data(env);
+    let now = env.ledger().timestamp();
+
+    if let Some(last) = data.last_rebase {
+        if now < last.saturating_add(REBASE_EPOCH_SECS) {
+            return Err(PiCoinError::RebaseEpochNotElapsed);
+        }
+    }
+
+    let max_step = data.rebase_index * MAX_REBASE_BPS / 10_000;
+    // A price too large to scale is past the cap anyway.
+    let target = data
+        .rebase_index
+        .checked_mul(market_price)
+        .map_or(i128::MAX, |scaled| scaled / data.peg_value);
+    let new_index = target.clamp(
+        data.rebase_index - max_step,
+        data.rebase_index + max_step,
+    );
+
+    env.events().publish(
+        (symbol_short!("rebase"),),
+        (data.rebase_index, new_index),
+    );
+
+    data.rebase_index = new_index;
+    data.last_rebase = Some(now);
+    env.storage().instance().set(&DataKey::Data, &data);
+
+    Ok(())
+}
+
+/* ============================================================
+   PARAMETER STORAGE
+   ============================================================ */
+
+fn read_parameters(env: &Env) -> Parameters {
+    env.storage()
+        .instance()
+        .get(&DataKey::Parameters)
+        .unwrap_or(Parameters {
+            mint_cap: DEFAULT_MINT_CAP,
+            collateral_ratio_bps: DEFAULT_COLLATERAL_RATIO_BPS,
+            peg_tolerance_bps: DEFAULT_PEG_TOLERANCE_BPS,
+            oracle_max_age_secs: DEFAULT_ORACLE_MAX_AGE_SECS,
+            fee_bps: 0,
+        })
+}
+
+fn check_parameter(param: &Parameter) -> bool {
+    match *param {
+        Parameter::MintCap(cap) => cap > 0,
+        Parameter::CollateralRatio(bps) => {
+            (MIN_COLLATERAL_RATIO_BPS..=MAX_COLLATERAL_RATIO_BPS)
+                .contains(&bps)
+        }
+        Parameter::PegTolerance(bps) => {
+            bps > 0 && bps <= MAX_PEG_TOLERANCE_BPS
+        }
+        Parameter::OracleStaleness(secs) => {
+            (MIN_ORACLE_MAX_AGE_SECS..=MAX_ORACLE_MAX_AGE_SECS).contains(&secs)
+        }
+        Parameter::FeeBps(bps) => bps <= MAX_FEE_BPS,
+    }
+}
+
+fn apply_parameter(env: &Env, param: Parameter) {
+    let mut params = read_parameters(env);
+
+    match param {
+        Parameter::MintCap(cap) => params.mint_cap = cap,
+        Parameter::CollateralRatio(bps) => params.collateral_ratio_bps = bps,
+        Parameter::PegTolerance(bps) => params.peg_tolerance_bps = bps,
+        Parameter::OracleStaleness(secs) => params.oracle_max_age_secs = secs,
+        Parameter::FeeBps(bps) => params.fee_bps = bps,
+    }
+
+    env.storage().instance().set(&DataKey::Parameters, &params);
+}
+
+/* ============================================================
+   SMART CONTRACT
+   ============================================================ */
+
+#[contract]
+pub struct PiCoinContract;
+
+/* ============================================================
+   IMPLEMENTATION
+   ============================================================ */
+
+#[contractimpl]
+impl PiCoinContract {
+
+    /* ---------- INITIALIZE ---------- */
+
+    pub fn initialize(env: Env, admin: Address) -> Result<(), PiCoinError> {
+        if env.storage().instance().has(&DataKey::Admin) {
+            return Err(PiCoinError::AlreadyInitialized);
+        }
+
+        admin.require_auth();
+
+        env.storage().instance().set(&DataKey::Admin, &admin);
+        env.storage().instance().set(
+            &DataKey::Governance,
+            &GovernanceMode::CoreTeamLed,
+        );
+        env.storage().instance().set(&DataKey::Data, &read_data(&env));
+        env.storage()
+            .instance()
+            .set(&DataKey::Version, &CONTRACT_VERSION);
+
+        env.events().publish(
+            (symbol_short!("init"), admin),
+            CONTRACT_VERSION,
+        );
+
+        Ok(())
+    }
+
+    /* ---------- UPGRADE (ADMIN ONLY) ---------- */
+
+    pub fn version(env: Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&DataKey::Version)
+            .unwrap_or(0)
+    }
+
+    pub fn upgrade(
+        env: Env,
+        new_wasm_hash: BytesN<32>,
+    ) -> Result<(), PiCoinError> {
+        require_admin(&env)?;
+
+        env.events().publish(
+            (symbol_short!("upgrade"),),
+            new_wasm_hash.clone(),
+        );
+
+        env.deployer().update_current_contract_wasm(new_wasm_hash);
+
+        Ok(())
+    }
+
+    pub fn migrate(env: Env) -> Result<u32, PiCoinError> {
+        require_admin(&env)?;
+
+        let stored: u32 = env
+            .storage()
+            .instance()
+            .get(&DataKey::Version)
+            .unwrap_or(0);
+
+        if stored < CONTRACT_VERSION {
+            env.storage()
+                .instance()
+                .set(&DataKey::Version, &CONTRACT_VERSION);
+
+            env.events().publish(
+                (symbol_short!("migrate"),),
+                (stored, CONTRACT_VERSION),
+            );
+        }
+
+        Ok(CONTRACT_VERSION)
+    }
+
+    /* ---------- QUANTUM KEY REGISTRY ---------- */
+
+    /// Registers the owner's first key, or a fresh key once
+    /// `PQ_REREGISTER_DELAY_LEDGERS` have passed since revocation.
+    /// Replacing a live key goes through `rotate_quantum_key`.
+    pub fn register_quantum_key(
+        env: Env,
+        owner: Address,
+        algorithm: PqAlgorithm,
+        key_hash: BytesN<32>,
+        rotation_commitment: BytesN<32>,
+    ) -> Result<u32, PiCoinError> {
+        owner.require_auth();
+
+        if active_quantum_key(&env, &owner).is_some() {
+            return Err(PiCoinError::QuantumKeyActive);
+        }
+
+        let last_revoked = quantum_key_count(&env, &owner)
+            .checked_sub(1)
+            .and_then(|last| read_quantum_key(&env, &owner, last))
+            .and_then(|record| record.revoked_at);
+
+        if let Some(revoked_at) = last_revoked {
+            if env.ledger().sequence()
+                < revoked_at.saturating_add(PQ_REREGISTER_DELAY_LEDGERS)
+            {
+                return Err(PiCoinError::ReregistrationTooSoon);
+            }
+        }
+
+        let index = append_quantum_key(
+            &env,
+            &owner,
+            algorithm,
+            &key_hash,
+            &rotation_commitment,
+        );
+
+        env.events().publish(
+            (symbol_short!("pq_key"), owner),
+            (index, algorithm, key_hash),
+        );
+
+        Ok(index)
+    }
+
+    /// Replaces the active key. `rotation_secret` must open the previous
+    /// key's `rotation_commitment`; the new key commits to the next secret.
+    pub fn rotate_quantum_key(
+        env: Env,
+        owner: Address,
+        algorithm: PqAlgorithm,
+        key_hash: BytesN<32>,
+        rotation_commitment: BytesN<32>,
+        rotation_secret: BytesN<32>,
+    ) -> Result<u32, PiCoinError> {
+        owner.require_auth();
+
+        let (previous_index, mut previous) = active_quantum_key(&env, &owner)
+            .ok_or(PiCoinError::NoActiveQuantumKey)?;
+
+        let opened = self::rotation_commitment(&env, &rotation_secret);
+        if opened != previous.rotation_commitment {
+            return Err(PiCoinError::RotationNotEndorsed);
+        }
+
+        previous.revoked_at = Some(env.ledger().sequence());
+        write_quantum_key(&env, &owner, previous_index, &previous);
+
+        let index = append_quantum_key(
+            &env,
+            &owner,
+            algorithm,
+            &key_hash,
+            &rotation_commitment,
+        );
+
+        env.events().publish(
+            (symbol_short!("pq_rotate"), owner),
+            (previous_index, index, key_hash),
+        );
+
+        Ok(index)
+    }
+
+    pub fn revoke_quantum_key(
+        env: Env,
+        owner: Address,
+    ) -> Result<(), PiCoinError> {
+        owner.require_auth();
+
+        let (index, mut record) = active_quantum_key(&env, &owner)
+            .ok_or(PiCoinError::NoActiveQuantumKey)?;
+
+        record.revoked_at = Some(env.ledger().sequence());
+        write_quantum_key(&env, &owner, index, &record);
+
+        env.events().publish((symbol_short!("pq_revoke"), owner), index);
+
+        Ok(())
+    }
+
+    /* ---------- MINT (ADMIN ONLY) ---------- */
+
+    pub fn mint(
+        env: Env,
+        to: Address,
+        amount: i128,
+    ) -> Result<(), PiCoinError> {
+        require_admin(&env)?;
+
+        if amount <= 0 {
+            return Err(PiCoinError::InvalidAmount);
+        }
+
+        let index = read_data(&env).rebase_index;
+        let shares = amount
+            .checked_mul(INDEX_SCALE)
+            .ok_or(PiCoinError::InvalidAmount)?
+            / index;
+
+        let balance: i128 = env
+            .storage()
+            .instance()
+            .get(&DataKey::Balance(to.clone()))
+            .unwrap_or(0);
+
+        let total: i128 = env
+            .storage()
+            .instance()
+            .get(&DataKey::TotalShares)
+            .unwrap_or(0);
+
+        // Cap what the shares are worth once minted, not the requested
+        // amount, so rounding at a non-unit index cannot slip past it.
+        let supply = total
+            .checked_add(shares)
+            .and_then(|shares| shares.checked_mul(index))
+            .ok_or(PiCoinError::MintCapExceeded)?
+            / INDEX_SCALE;
+        if supply > read_parameters(&env).mint_cap {
+            return Err(PiCoinError::MintCapExceeded);
+        }
+
+        env.storage()
+            .instance()
+            .set(&DataKey::Balance(to.clone()), &(balance + shares));
+        env.storage()
+            .instance()
+            .set(&DataKey::TotalShares, &(total + shares));
+
+        env.events().publish((symbol_short!("mint"), to), amount);
+
+        Ok(())
+    }
+
+    /* ---------- TRANSFER (QUANTUM-SAFE) ---------- */
+
+    pub fn transfer(
+        env: Env,
+        from: Address,
+        to: Address,
+        amount: i128,
+        nonce: u64,
+        sig: QuantumSignature,
+    ) -> Result<(), PiCoinError> {
+        from.require_auth();
+
+        if amount <= 0 {
+            return Err(PiCoinError::InvalidAmount);
+        }
+
+        verify_quantum_signature(&env, &from, &to, amount, nonce, &sig)?;
+
+        let index = read_data(&env).rebase_index;
+        let shares = to_shares(amount, index)?;
+
+        let from_shares: i128 = env
+            .storage()
+            .instance()
+            .get(&DataKey::Balance(from.clone()))
+            .unwrap_or(0);
+
+        if from_shares < shares {
+            return Err(PiCoinError::InsufficientBalance);
+        }
+
+        // The transfer fee is burned out of the moved shares; the transfer
+        // event carries the net amount the recipient receives.
+        let fee_bps = read_parameters(&env).fee_bps as i128;
+        let fee_shares = shares * fee_bps / 10_000;
+        let fee = to_amount(fee_shares, index);
+
+        let total: i128 = env
+            .storage()
+            .instance()
+            .get(&DataKey::TotalShares)
+            .unwrap_or(0);
+
+        env.storage()
+            .instance()
+            .set(&DataKey::Balance(from.clone()), &(from_shares - shares));
+
+        // Read after the debit so a self-transfer credits the debited balance.
+        let recipient_shares: i128 = env
+            .storage()
+            .instance()
+            .get(&DataKey::Balance(to.clone()))
+            .unwrap_or(0);
+
+        env.storage().instance().set(
+            &DataKey::Balance(to.clone()),
+            &(recipient_shares + shares - fee_shares),
+        );
+
+        if fee_shares > 0 {
+            env.storage()
+                .instance()
+                .set(&DataKey::TotalShares, &(total - fee_shares));
+
+            env.events()
+                .publish((symbol_short!("fee"), from.clone()), fee);
+        }
+
+        env.events().publish(
+            (symbol_short!("transfer"), from, to),
+            amount - fee,
+        );
+
+        Ok(())
+    }
+
+    /* ---------- GOVERNANCE ACTION (TIMELOCKED) ---------- */
+
+    /// Queues an approved action. It can be executed by anyone once the
+    /// timelock delay has passed, unless the guardian cancels it first.
+    pub fn governance_action(
+        env: Env,
+        action: ProposalAction,
+    ) -> Result<u32, PiCoinError> {
+        require_admin(&env)?;
+
+        // Reject malformed payloads now rather than after the delay.
+        match &action {
+            ProposalAction::Rebase(market_price) if *market_price <= 0 => {
+                return Err(PiCoinError::InvalidMarketPrice);
+            }
+            ProposalAction::ParameterUpdate(param)
+                if !check_parameter(param) =>
+            {
+                return Err(PiCoinError::ParameterOutOfBounds);
+            }
+            ProposalAction::UpdatePeg(peg_value) if *peg_value <= 0 => {
+                return Err(PiCoinError::InvalidPegValue);
+            }
+            ProposalAction::SetTimelockDelay(delay)
+                if *delay < MIN_TIMELOCK_DELAY =>
+            {
+                return Err(PiCoinError::TimelockDelayTooShort);
+            }
+            _ => {}
+        }
+
+        let id: u32 = env
+            .storage()
+            .instance()
+            .get(&DataKey::ActionCount)
+            .unwrap_or(0)
+            + 1;
+
+        let queued = QueuedAction {
+            action,
+            eta: env.ledger().timestamp() + Self::timelock_delay(env.clone()),
+        };
+
+        env.storage().instance().set(&DataKey::ActionCount, &id);
+        env.storage()
+            .persistent()
+            .set(&DataKey::QueuedAction(id), &queued);
+
+        env.events().publish(
+            (symbol_short!("gov"), symbol_short!("queued"), id),
+            (queued.action, queued.eta),
+        );
+
+        Ok(id)
+    }
+
+    pub fn execute_action(env: Env, id: u32) -> Result<(), PiCoinError> {
+        let queued: QueuedAction = env
+            .storage()
+            .persistent()
+            .get(&DataKey::QueuedAction(id))
+            .ok_or(PiCoinError::ActionNotQueued)?;
+
+        if env.ledger().timestamp() < queued.eta {
+            return Err(PiCoinError::TimelockNotExpired);
+        }
+
+        env.storage()
+            .persistent()
+            .remove(&DataKey::QueuedAction(id));
+
+        match queued.action.clone() {
+            ProposalAction::Rebase(market_price) => {
+                apply_rebase(&env, market_price)?;
+            }
+            ProposalAction::ParameterUpdate(param) => {
+                apply_parameter(&env, param);
+            }
+            ProposalAction::RotateOracle(oracle) => {
+                env.storage().instance().set(&DataKey::Oracle, &oracle);
+            }
+            ProposalAction::UpdatePeg(peg_value) => {
+                let mut data = read_data(&env);
+                data.peg_value = peg_value;
+                env.storage().instance().set(&DataKey::Data, &data);
+            }
+            ProposalAction::SetGuardian(guardian) => {
+                env.storage().instance().set(&DataKey::Guardian, &guardian);
+
+                env.events().publish(
+                    (symbol_short!("gov"), symbol_short!("guardian")),
+                    guardian,
+                );
+            }
+            ProposalAction::SetTimelockDelay(delay) => {
+                env.storage().instance().set(&DataKey::TimelockDelay, &delay);
+
+                env.events().publish(
+                    (symbol_short!("gov"), symbol_short!("delay")),
+                    delay,
+                );
+            }
+        }
+
+        env.events().publish(
+            (symbol_short!("gov"), symbol_short!("executed"), id),
+            queued.action,
+        );
+
+        Ok(())
+    }
+
+    /// Rebases towards the peg using the oracle installed by
+    /// `RotateOracle`. Callable by anyone; still one rebase per epoch.
+    /// A round older than `oracle_max_age_secs` is rejected, and a price
+    /// within `peg_tolerance_bps` of the peg leaves the index unchanged.
+    pub fn rebase_from_oracle(env: Env) -> Result<i128, PiCoinError> {
+        let params = read_parameters(&env);
+        let round = read_oracle_round(&env)?;
+        let age = env.ledger().timestamp().saturating_sub(round.updated_at);
+
+        if age > params.oracle_max_age_secs {
+            return Err(PiCoinError::OracleStale);
+        }
+
+        let peg = read_data(&env).peg_value;
+        let deviation = (round.answer - peg).abs().saturating_mul(10_000);
+
+        if deviation > peg.saturating_mul(params.peg_tolerance_bps as i128) {
+            apply_rebase(&env, round.answer)?;
+        }
+
+        Ok(read_data(&env).rebase_index)
+    }
+
+    pub fn cancel_action(env: Env, id: u32) -> Result<(), PiCoinError> {
+        let guardian: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Guardian)
+            .ok_or(PiCoinError::GuardianNotSet)?;
+
+        guardian.require_auth();
+
+        if !env.storage().persistent().has(&DataKey::QueuedAction(id)) {
+            return Err(PiCoinError::ActionNotQueued);
+        }
+
+        env.storage()
+            .persistent()
+            .remove(&DataKey::QueuedAction(id));
+
+        env.events().publish(
+            (symbol_short!("gov"), symbol_short!("cancel"), id),
+            guardian,
+        );
+
+        Ok(())
+    }
+
+    /* ---------- VIEW ---------- */
+
+    pub fn quantum_key(env: Env, owner: Address) -> Option<QuantumKeyRecord> {
+        active_quantum_key(&env, &owner).map(|(_, record)| record)
+    }
+
+    pub fn quantum_key_count(env: Env, owner: Address) -> u32 {
+        quantum_key_count(&env, &owner)
+    }
+
+    pub fn quantum_key_at(
+        env: Env,
+        owner: Address,
+        index: u32,
+    ) -> Option<QuantumKeyRecord> {
+        read_quantum_key(&env, &owner, index)
+    }
+
+    /// Key that was active for `owner` at `ledger`, if any.
+    pub fn quantum_key_at_ledger(
+        env: Env,
+        owner: Address,
+        ledger: u32,
+    ) -> Option<QuantumKeyRecord> {
+        let count = quantum_key_count(&env, &owner);
+
+        (0..count)
+            .rev()
+            .filter_map(|index| read_quantum_key(&env, &owner, index))
+            .find(|record| record.activated_at <= ledger)
+            .filter(|record| record.revoked_at.is_none_or(|at| ledger < at))
+    }
+
+    pub fn nonce_of(env: Env, owner: Address) -> u64 {
+        read_nonce(&env, &owner)
+    }
+
+    /// Digest `from`'s active key must sign for a transfer at `nonce_of`.
+    pub fn transfer_digest(
+        env: Env,
+        from: Address,
+        to: Address,
+        amount: i128,
+        nonce: u64,
+    ) -> Option<BytesN<32>> {
+        active_quantum_key(&env, &from).map(|(_, key)| {
+            transfer_digest(&env, &key.key_hash, &from, &to, amount, nonce)
+        })
+    }
+
+    pub fn rotation_commitment(env: Env, secret: BytesN<32>) -> BytesN<32> {
+        rotation_commitment(&env, &secret)
+    }
+
+    pub fn guardian(env: Env) -> Option<Address> {
+        env.storage().instance().get(&DataKey::Guardian)
+    }
+
+    pub fn timelock_delay(env: Env) -> u64 {
+        env.storage()
+            .instance()
+            .get(&DataKey::TimelockDelay)
+            .unwrap_or(DEFAULT_TIMELOCK_DELAY)
+    }
+
+    pub fn queued_action(env: Env, id: u32) -> Option<QueuedAction> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::QueuedAction(id))
+    }
+
+    pub fn rebase_index(env: Env) -> i128 {
+        read_data(&env).rebase_index
+    }
+
+    pub fn peg_value(env: Env) -> i128 {
+        read_data(&env).peg_value
+    }
+
+    pub fn shares_of(env: Env, owner: Address) -> i128 {
+        env.storage()
+            .instance()
+            .get(&DataKey::Balance(owner))
+            .unwrap_or(0)
+    }
+
+    pub fn total_supply(env: Env) -> i128 {
+        let shares: i128 = env
+            .storage()
+            .instance()
+            .get(&DataKey::TotalShares)
+            .unwrap_or(0);
+
+        to_amount(shares, read_data(&env).rebase_index)
+    }
+
+    pub fn get_parameters(env: Env) -> Parameters {
+        read_parameters(&env)
+    }
+
+    pub fn oracle(env: Env) -> Option<Address> {
+        env.storage().instance().get(&DataKey::Oracle)
+    }
+
+    pub fn balance_of(env: Env, owner: Address) -> i128 {
+        to_amount(
+            Self::shares_of(env.clone(), owner),
+            read_data(&env).rebase_index,
+        )
+    }
+}
+
+/* ============================================================
+   TESTS
+   ============================================================ */
+
+#[cfg(test)]
+mod test;
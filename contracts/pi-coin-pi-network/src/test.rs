@@ -0,0 +1,731 @@
+#![cfg(test)]
+
+use super::*;
+use soroban_sdk::{
+    testutils::{Address as _, Events, Ledger},
+    vec, Env, IntoVal,
+};
+
+#[contract]
+pub struct MockOracle;
+
+#[contractimpl]
+impl MockOracle {
+    pub fn set_answer(env: Env, answer: i128) {
+        env.storage().instance().set(&symbol_short!("answer"), &answer);
+        env.storage()
+            .instance()
+            .set(&symbol_short!("ts"), &env.ledger().timestamp());
+    }
+
+    pub fn decimals(_env: Env) -> u32 {
+        PEG_DECIMALS
+    }
+
+    pub fn latest_round_data(env: Env) -> RoundData {
+        let answer = env.storage().instance().get(&symbol_short!("answer"));
+        let updated_at = env.storage().instance().get(&symbol_short!("ts"));
+
+        RoundData {
+            round_id: 1,
+            answer: answer.unwrap(),
+            started_at: updated_at.unwrap(),
+            updated_at: updated_at.unwrap(),
+            answered_in_round: 1,
+        }
+    }
+}
+
+fn setup(env: &Env) -> (PiCoinContractClient<'_>, Address) {
+    env.mock_all_auths();
+
+    let contract_id = env.register(PiCoinContract, ());
+    let client = PiCoinContractClient::new(env, &contract_id);
+    let admin = Address::generate(env);
+
+    client.initialize(&admin);
+
+    (client, admin)
+}
+
+fn key_hash(env: &Env, seed: u8) -> BytesN<32> {
+    BytesN::from_array(env, &[seed; 32])
+}
+
+// Rotation secret held alongside the key `key_hash(env, seed)`.
+fn secret(env: &Env, seed: u8) -> BytesN<32> {
+    BytesN::from_array(env, &[seed + 100; 32])
+}
+
+fn commitment(client: &PiCoinContractClient<'_>, seed: u8) -> BytesN<32> {
+    client.rotation_commitment(&secret(&client.env, seed))
+}
+
+#[test]
+fn test_error_codes_are_stable() {
+    assert_eq!(PiCoinError::InvalidQuantumSignature as u32, 1);
+    assert_eq!(PiCoinError::NonceReused as u32, 2);
+    assert_eq!(PiCoinError::InvalidNonce as u32, 3);
+    assert_eq!(PiCoinError::InsufficientBalance as u32, 4);
+    assert_eq!(PiCoinError::AlreadyInitialized as u32, 5);
+    assert_eq!(PiCoinError::NotInitialized as u32, 6);
+    assert_eq!(PiCoinError::QuantumKeyActive as u32, 7);
+    assert_eq!(PiCoinError::NoActiveQuantumKey as u32, 8);
+    assert_eq!(PiCoinError::RotationNotEndorsed as u32, 9);
+    assert_eq!(PiCoinError::InvalidAmount as u32, 10);
+    assert_eq!(PiCoinError::MintCapExceeded as u32, 11);
+    assert_eq!(PiCoinError::InvalidMarketPrice as u32, 12);
+    assert_eq!(PiCoinError::ParameterOutOfBounds as u32, 13);
+    assert_eq!(PiCoinError::ActionNotQueued as u32, 14);
+    assert_eq!(PiCoinError::TimelockNotExpired as u32, 15);
+    assert_eq!(PiCoinError::GuardianNotSet as u32, 16);
+    assert_eq!(PiCoinError::TimelockDelayTooShort as u32, 17);
+    assert_eq!(PiCoinError::RebaseEpochNotElapsed as u32, 18);
+    assert_eq!(PiCoinError::ReregistrationTooSoon as u32, 19);
+    assert_eq!(PiCoinError::OracleNotSet as u32, 20);
+    assert_eq!(PiCoinError::OracleUnavailable as u32, 21);
+    assert_eq!(PiCoinError::InvalidPegValue as u32, 22);
+    assert_eq!(PiCoinError::OracleStale as u32, 23);
+}
+
+#[test]
+fn test_lifecycle_errors() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(PiCoinContract, ());
+    let client = PiCoinContractClient::new(&env, &contract_id);
+    let user = Address::generate(&env);
+
+    assert_eq!(
+        client.try_mint(&user, &1),
+        Err(Ok(PiCoinError::NotInitialized))
+    );
+
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+    assert_eq!(
+        client.try_initialize(&admin),
+        Err(Ok(PiCoinError::AlreadyInitialized))
+    );
+    assert_eq!(
+        client.try_mint(&user, &0),
+        Err(Ok(PiCoinError::InvalidAmount))
+    );
+    assert_eq!(
+        client.try_mint(&user, &(DEFAULT_MINT_CAP + 1)),
+        Err(Ok(PiCoinError::MintCapExceeded))
+    );
+}
+
+#[test]
+fn test_transfer_errors() {
+    let env = Env::default();
+    let (client, _) = setup(&env);
+
+    let from = Address::generate(&env);
+    let to = Address::generate(&env);
+    let forged = QuantumSignature { hash: key_hash(&env, 9) };
+
+    client.mint(&from, &1_000);
+    assert_eq!(
+        client.try_transfer(&from, &to, &100, &0, &forged),
+        Err(Ok(PiCoinError::InvalidQuantumSignature))
+    );
+
+    let algorithm = PqAlgorithm::MlDsa65;
+    client.register_quantum_key(
+        &from,
+        &algorithm,
+        &key_hash(&env, 1),
+        &commitment(&client, 1),
+    );
+    assert_eq!(
+        client.try_transfer(&from, &to, &100, &0, &forged),
+        Err(Ok(PiCoinError::InvalidQuantumSignature))
+    );
+
+    let sig = QuantumSignature {
+        hash: client.transfer_digest(&from, &to, &100, &0).unwrap(),
+    };
+    client.transfer(&from, &to, &100, &0, &sig);
+    assert_eq!(client.balance_of(&to), 100);
+    assert_eq!(client.nonce_of(&from), 1);
+
+    assert_eq!(
+        client.try_transfer(&from, &to, &100, &0, &sig),
+        Err(Ok(PiCoinError::NonceReused))
+    );
+    assert_eq!(
+        client.try_transfer(&from, &to, &100, &5, &sig),
+        Err(Ok(PiCoinError::InvalidNonce))
+    );
+
+    let too_much = QuantumSignature {
+        hash: client.transfer_digest(&from, &to, &5_000, &1).unwrap(),
+    };
+    assert_eq!(
+        client.try_transfer(&from, &to, &5_000, &1, &too_much),
+        Err(Ok(PiCoinError::InsufficientBalance))
+    );
+}
+
+#[test]
+fn test_oversized_amounts_are_rejected() {
+    let env = Env::default();
+    let (client, _) = setup(&env);
+
+    let from = Address::generate(&env);
+    let to = Address::generate(&env);
+    assert_eq!(
+        client.try_mint(&from, &i128::MAX),
+        Err(Ok(PiCoinError::InvalidAmount))
+    );
+
+    client.mint(&from, &1_000);
+    client.register_quantum_key(
+        &from,
+        &PqAlgorithm::MlDsa65,
+        &key_hash(&env, 1),
+        &commitment(&client, 1),
+    );
+
+    let sig = QuantumSignature {
+        hash: client.transfer_digest(&from, &to, &i128::MAX, &0).unwrap(),
+    };
+    assert_eq!(
+        client.try_transfer(&from, &to, &i128::MAX, &0, &sig),
+        Err(Ok(PiCoinError::InvalidAmount))
+    );
+    assert_eq!(client.balance_of(&from), 1_000);
+}
+
+#[test]
+fn test_quantum_key_errors() {
+    let env = Env::default();
+    let (client, _) = setup(&env);
+
+    let owner = Address::generate(&env);
+    let first = key_hash(&env, 1);
+    let second = key_hash(&env, 2);
+    let (old_alg, new_alg) = (PqAlgorithm::MlDsa44, PqAlgorithm::MlDsa87);
+
+    assert_eq!(
+        client.try_revoke_quantum_key(&owner),
+        Err(Ok(PiCoinError::NoActiveQuantumKey))
+    );
+
+    client.register_quantum_key(
+        &owner,
+        &old_alg,
+        &first,
+        &commitment(&client, 1),
+    );
+    assert_eq!(
+        client.try_register_quantum_key(
+            &owner,
+            &old_alg,
+            &second,
+            &commitment(&client, 2),
+        ),
+        Err(Ok(PiCoinError::QuantumKeyActive))
+    );
+
+    env.ledger().with_mut(|li| li.sequence_number += 10);
+    let rotated_at = env.ledger().sequence();
+    client.rotate_quantum_key(
+        &owner,
+        &new_alg,
+        &second,
+        &commitment(&client, 2),
+        &secret(&env, 1),
+    );
+
+    assert_eq!(client.quantum_key_count(&owner), 2);
+    assert_eq!(client.quantum_key(&owner).unwrap().key_hash, second);
+    assert_eq!(
+        client
+            .quantum_key_at_ledger(&owner, &(rotated_at - 1))
+            .unwrap()
+            .key_hash,
+        first
+    );
+}
+
+fn signed_transfer(
+    client: &PiCoinContractClient<'_>,
+    from: &Address,
+    to: &Address,
+    amount: i128,
+) {
+    let nonce = client.nonce_of(from);
+    let sig = QuantumSignature {
+        hash: client.transfer_digest(from, to, &amount, &nonce).unwrap(),
+    };
+
+    client.transfer(from, to, &amount, &nonce, &sig);
+}
+
+fn execute(
+    env: &Env,
+    client: &PiCoinContractClient<'_>,
+    action: ProposalAction,
+) {
+    let id = client.governance_action(&action);
+    env.ledger()
+        .with_mut(|li| li.timestamp += DEFAULT_TIMELOCK_DELAY);
+    client.execute_action(&id);
+}
+
+fn rebase_to(env: &Env, client: &PiCoinContractClient<'_>, price: i128) {
+    execute(env, client, ProposalAction::Rebase(price));
+}
+
+#[test]
+fn test_self_transfer_keeps_balance() {
+    let env = Env::default();
+    let (client, _) = setup(&env);
+
+    let owner = Address::generate(&env);
+    client.mint(&owner, &1_000);
+    client.register_quantum_key(
+        &owner,
+        &PqAlgorithm::MlDsa65,
+        &key_hash(&env, 1),
+        &commitment(&client, 1),
+    );
+
+    signed_transfer(&client, &owner, &owner, 1_000);
+
+    assert_eq!(client.balance_of(&owner), 1_000);
+    assert_eq!(client.total_supply(), 1_000);
+}
+
+#[test]
+fn test_transfer_fee_is_burned() {
+    let env = Env::default();
+    let (client, _) = setup(&env);
+
+    let from = Address::generate(&env);
+    let to = Address::generate(&env);
+    client.mint(&from, &10_000);
+    client.register_quantum_key(
+        &from,
+        &PqAlgorithm::MlDsa65,
+        &key_hash(&env, 1),
+        &commitment(&client, 1),
+    );
+    execute(
+        &env,
+        &client,
+        ProposalAction::ParameterUpdate(Parameter::FeeBps(250)),
+    );
+
+    signed_transfer(&client, &from, &to, 4_000);
+
+    assert_eq!(
+        env.events().all(),
+        vec![
+            &env,
+            (
+                client.address.clone(),
+                (symbol_short!("fee"), from.clone()).into_val(&env),
+                100_i128.into_val(&env),
+            ),
+            (
+                client.address.clone(),
+                (symbol_short!("transfer"), from.clone(), to.clone())
+                    .into_val(&env),
+                3_900_i128.into_val(&env),
+            ),
+        ]
+    );
+
+    assert_eq!(client.balance_of(&from), 6_000);
+    assert_eq!(client.balance_of(&to), 3_900);
+    assert_eq!(client.total_supply(), 9_900);
+}
+
+#[test]
+fn test_rebase_scales_share_balances() {
+    let env = Env::default();
+    let (client, _) = setup(&env);
+
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    client.mint(&alice, &1_000_000);
+    client.register_quantum_key(
+        &alice,
+        &PqAlgorithm::MlDsa65,
+        &key_hash(&env, 1),
+        &commitment(&client, 1),
+    );
+
+    // Market at 2x the peg: the index moves by the 5% epoch cap only
+    env.ledger().with_mut(|li| li.timestamp = 1);
+    rebase_to(&env, &client, DEFAULT_PEG_VALUE * 2);
+    assert_eq!(client.rebase_index(), INDEX_SCALE * 105 / 100);
+    assert_eq!(client.shares_of(&alice), 1_000_000);
+    assert_eq!(client.balance_of(&alice), 1_050_000);
+    assert_eq!(client.total_supply(), 1_050_000);
+
+    // Shares round up, so the sender never pays less than the amount
+    signed_transfer(&client, &alice, &bob, 100);
+    assert_eq!(client.shares_of(&bob), 96);
+    assert_eq!(client.shares_of(&alice), 1_000_000 - 96);
+    assert_eq!(client.balance_of(&bob), 100);
+
+    // Next epoch, 1% below the peg: within the cap, so applied exactly
+    env.ledger()
+        .with_mut(|li| li.timestamp += REBASE_EPOCH_SECS);
+    rebase_to(&env, &client, DEFAULT_PEG_VALUE * 99 / 100);
+    assert_eq!(client.rebase_index(), 1_039_500_000);
+    assert_eq!(client.balance_of(&alice), 1_039_400);
+}
+
+#[test]
+fn test_mint_after_rebase() {
+    let env = Env::default();
+    let (client, _) = setup(&env);
+
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    execute(
+        &env,
+        &client,
+        ProposalAction::ParameterUpdate(Parameter::MintCap(1_100_000)),
+    );
+    client.mint(&alice, &1_000_000);
+    rebase_to(&env, &client, DEFAULT_PEG_VALUE * 2);
+    assert_eq!(client.rebase_index(), INDEX_SCALE * 105 / 100);
+
+    // Minted at the current index; the share rounds down
+    client.mint(&bob, &1_000);
+    assert_eq!(client.shares_of(&bob), 952);
+    assert_eq!(client.balance_of(&bob), 999);
+    assert_eq!(client.total_supply(), 1_050_999);
+
+    // The cap applies to what the new shares are worth
+    assert_eq!(
+        client.try_mint(&bob, &49_002),
+        Err(Ok(PiCoinError::MintCapExceeded))
+    );
+    client.mint(&bob, &49_001);
+    assert_eq!(client.total_supply(), 1_099_999);
+}
+
+#[test]
+fn test_rotation_needs_the_previous_secret() {
+    let env = Env::default();
+    let (client, _) = setup(&env);
+
+    let owner = Address::generate(&env);
+    let algorithm = PqAlgorithm::MlDsa65;
+    client.register_quantum_key(
+        &owner,
+        &algorithm,
+        &key_hash(&env, 1),
+        &commitment(&client, 1),
+    );
+
+    // Owner auth is mocked: a caller holding the account key but not the
+    // secret can only try public values, which never open the commitment.
+    let record = client.quantum_key(&owner).unwrap();
+    for guess in [
+        record.key_hash.clone(),
+        record.rotation_commitment.clone(),
+        secret(&env, 9),
+    ] {
+        assert_eq!(
+            client.try_rotate_quantum_key(
+                &owner,
+                &algorithm,
+                &key_hash(&env, 9),
+                &commitment(&client, 9),
+                &guess,
+            ),
+            Err(Ok(PiCoinError::RotationNotEndorsed))
+        );
+    }
+    assert_eq!(client.quantum_key(&owner), Some(record));
+
+    client.rotate_quantum_key(
+        &owner,
+        &algorithm,
+        &key_hash(&env, 2),
+        &commitment(&client, 2),
+        &secret(&env, 1),
+    );
+
+    // A revealed secret is spent: the next rotation needs the new one
+    assert_eq!(
+        client.try_rotate_quantum_key(
+            &owner,
+            &algorithm,
+            &key_hash(&env, 3),
+            &commitment(&client, 3),
+            &secret(&env, 1),
+        ),
+        Err(Ok(PiCoinError::RotationNotEndorsed))
+    );
+}
+
+#[test]
+fn test_reregistration_waits_after_revocation() {
+    let env = Env::default();
+    let (client, _) = setup(&env);
+
+    let owner = Address::generate(&env);
+    let algorithm = PqAlgorithm::MlDsa65;
+    client.register_quantum_key(
+        &owner,
+        &algorithm,
+        &key_hash(&env, 1),
+        &commitment(&client, 1),
+    );
+    client.revoke_quantum_key(&owner);
+
+    // Revoke + register would otherwise bypass the rotation secret
+    assert_eq!(
+        client.try_register_quantum_key(
+            &owner,
+            &algorithm,
+            &key_hash(&env, 2),
+            &commitment(&client, 2),
+        ),
+        Err(Ok(PiCoinError::ReregistrationTooSoon))
+    );
+    assert_eq!(client.quantum_key(&owner), None);
+
+    env.ledger().with_mut(|li| {
+        li.sequence_number += PQ_REREGISTER_DELAY_LEDGERS
+    });
+    assert_eq!(
+        client.register_quantum_key(
+            &owner,
+            &algorithm,
+            &key_hash(&env, 2),
+            &commitment(&client, 2),
+        ),
+        1
+    );
+}
+
+#[test]
+fn test_governance_actions_take_effect() {
+    let env = Env::default();
+    let (client, _) = setup(&env);
+    env.ledger().with_mut(|li| li.timestamp = 1);
+
+    assert_eq!(
+        client.try_rebase_from_oracle(),
+        Err(Ok(PiCoinError::OracleNotSet))
+    );
+
+    let oracle_id = env.register(MockOracle, ());
+    let oracle = MockOracleClient::new(&env, &oracle_id);
+    execute(&env, &client, ProposalAction::RotateOracle(oracle_id.clone()));
+    assert_eq!(client.oracle(), Some(oracle_id));
+
+    // Market 2% above the peg expands the index by 2%
+    oracle.set_answer(&(DEFAULT_PEG_VALUE * 102 / 100));
+    assert_eq!(client.rebase_from_oracle(), 1_020_000_000);
+    assert_eq!(
+        client.try_rebase_from_oracle(),
+        Err(Ok(PiCoinError::RebaseEpochNotElapsed))
+    );
+
+    assert_eq!(
+        client.try_governance_action(&ProposalAction::UpdatePeg(0)),
+        Err(Ok(PiCoinError::InvalidPegValue))
+    );
+
+    // Doubling the peg leaves the market far below it: capped contraction
+    let new_peg = DEFAULT_PEG_VALUE * 2;
+    execute(&env, &client, ProposalAction::UpdatePeg(new_peg));
+    assert_eq!(client.peg_value(), new_peg);
+    oracle.set_answer(&(DEFAULT_PEG_VALUE * 102 / 100));
+    assert_eq!(client.rebase_from_oracle(), 969_000_000);
+}
+
+#[test]
+fn test_oracle_parameters_gate_rebase() {
+    let env = Env::default();
+    let (client, _) = setup(&env);
+    env.ledger().with_mut(|li| li.timestamp = 1);
+
+    let oracle_id = env.register(MockOracle, ());
+    let oracle = MockOracleClient::new(&env, &oracle_id);
+    execute(&env, &client, ProposalAction::RotateOracle(oracle_id));
+
+    // 0.5% off the peg is inside the default 1% tolerance
+    let price = DEFAULT_PEG_VALUE * 1_005 / 1_000;
+    oracle.set_answer(&price);
+    assert_eq!(client.rebase_from_oracle(), INDEX_SCALE);
+
+    env.ledger().with_mut(|li| {
+        li.timestamp += DEFAULT_ORACLE_MAX_AGE_SECS + 1
+    });
+    assert_eq!(
+        client.try_rebase_from_oracle(),
+        Err(Ok(PiCoinError::OracleStale))
+    );
+
+    execute(
+        &env,
+        &client,
+        ProposalAction::ParameterUpdate(Parameter::PegTolerance(10)),
+    );
+    execute(
+        &env,
+        &client,
+        ProposalAction::ParameterUpdate(Parameter::OracleStaleness(
+            MAX_ORACLE_MAX_AGE_SECS,
+        )),
+    );
+    let params = client.get_parameters();
+    assert_eq!(params.peg_tolerance_bps, 10);
+    assert_eq!(params.oracle_max_age_secs, MAX_ORACLE_MAX_AGE_SECS);
+
+    // Stale under the old limit, fresh under the new one
+    oracle.set_answer(&price);
+    env.ledger().with_mut(|li| {
+        li.timestamp += DEFAULT_ORACLE_MAX_AGE_SECS + 1
+    });
+    assert_eq!(client.rebase_from_oracle(), 1_005_000_000);
+
+    for param in [
+        Parameter::MintCap(0),
+        Parameter::CollateralRatio(MIN_COLLATERAL_RATIO_BPS - 1),
+        Parameter::CollateralRatio(MAX_COLLATERAL_RATIO_BPS + 1),
+        Parameter::PegTolerance(0),
+        Parameter::PegTolerance(MAX_PEG_TOLERANCE_BPS + 1),
+        Parameter::OracleStaleness(MIN_ORACLE_MAX_AGE_SECS - 1),
+    ] {
+        assert_eq!(
+            client.try_governance_action(
+                &ProposalAction::ParameterUpdate(param)
+            ),
+            Err(Ok(PiCoinError::ParameterOutOfBounds))
+        );
+    }
+
+    assert_eq!(
+        client.get_parameters().collateral_ratio_bps,
+        DEFAULT_COLLATERAL_RATIO_BPS
+    );
+    execute(
+        &env,
+        &client,
+        ProposalAction::ParameterUpdate(Parameter::CollateralRatio(
+            MIN_COLLATERAL_RATIO_BPS,
+        )),
+    );
+    assert_eq!(
+        client.get_parameters().collateral_ratio_bps,
+        MIN_COLLATERAL_RATIO_BPS
+    );
+}
+
+#[test]
+fn test_guardian_cancels_queued_action() {
+    let env = Env::default();
+    let (client, _) = setup(&env);
+
+    let guardian = Address::generate(&env);
+    execute(&env, &client, ProposalAction::SetGuardian(guardian.clone()));
+    assert_eq!(client.guardian(), Some(guardian.clone()));
+
+    let id = client.governance_action(&ProposalAction::UpdatePeg(1));
+    client.cancel_action(&id);
+    assert_eq!(client.queued_action(&id), None);
+
+    // Replacing the guardian or shortening the delay is cancellable too
+    let replace = client.governance_action(&ProposalAction::SetGuardian(
+        Address::generate(&env),
+    ));
+    let shorten = client.governance_action(
+        &ProposalAction::SetTimelockDelay(MIN_TIMELOCK_DELAY),
+    );
+    client.cancel_action(&replace);
+    client.cancel_action(&shorten);
+
+    env.ledger()
+        .with_mut(|li| li.timestamp += DEFAULT_TIMELOCK_DELAY);
+    for id in [id, replace, shorten] {
+        assert_eq!(
+            client.try_execute_action(&id),
+            Err(Ok(PiCoinError::ActionNotQueued))
+        );
+    }
+    assert_eq!(client.peg_value(), DEFAULT_PEG_VALUE);
+    assert_eq!(client.guardian(), Some(guardian));
+    assert_eq!(client.timelock_delay(), DEFAULT_TIMELOCK_DELAY);
+
+    execute(
+        &env,
+        &client,
+        ProposalAction::SetTimelockDelay(MIN_TIMELOCK_DELAY),
+    );
+    assert_eq!(client.timelock_delay(), MIN_TIMELOCK_DELAY);
+}
+
+#[test]
+fn test_timelock_errors() {
+    let env = Env::default();
+    let (client, _) = setup(&env);
+
+    assert_eq!(
+        client.try_governance_action(&ProposalAction::Rebase(0)),
+        Err(Ok(PiCoinError::InvalidMarketPrice))
+    );
+    assert_eq!(
+        client.try_governance_action(&ProposalAction::ParameterUpdate(
+            Parameter::FeeBps(MAX_FEE_BPS + 1)
+        )),
+        Err(Ok(PiCoinError::ParameterOutOfBounds))
+    );
+    assert_eq!(
+        client.try_governance_action(&ProposalAction::SetTimelockDelay(
+            MIN_TIMELOCK_DELAY - 1
+        )),
+        Err(Ok(PiCoinError::TimelockDelayTooShort))
+    );
+
+    let id = client.governance_action(&ProposalAction::ParameterUpdate(
+        Parameter::FeeBps(25),
+    ));
+    assert_eq!(
+        client.try_execute_action(&id),
+        Err(Ok(PiCoinError::TimelockNotExpired))
+    );
+    assert_eq!(
+        client.try_cancel_action(&id),
+        Err(Ok(PiCoinError::GuardianNotSet))
+    );
+
+    env.ledger()
+        .with_mut(|li| li.timestamp += DEFAULT_TIMELOCK_DELAY);
+    client.execute_action(&id);
+    assert_eq!(client.get_parameters().fee_bps, 25);
+
+    assert_eq!(
+        client.try_execute_action(&id),
+        Err(Ok(PiCoinError::ActionNotQueued))
+    );
+
+    let first = client.governance_action(&ProposalAction::Rebase(
+        DEFAULT_PEG_VALUE * 2,
+    ));
+    let second = client.governance_action(&ProposalAction::Rebase(
+        DEFAULT_PEG_VALUE * 2,
+    ));
+    env.ledger()
+        .with_mut(|li| li.timestamp += DEFAULT_TIMELOCK_DELAY);
+
+    client.execute_action(&first);
+    assert_eq!(
+        client.try_execute_action(&second),
+        Err(Ok(PiCoinError::RebaseEpochNotElapsed))
+    );
+}
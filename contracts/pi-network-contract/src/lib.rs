@@ -1,8 +1,8 @@
 #![no_std]
 
 use soroban_sdk::{
-    contract, contractimpl, contracttype,
-    Env, Address, Symbol, Bytes,
+    contract, contracterror, contractimpl, contracttype, symbol_short,
+    Env, Address, Symbol, Bytes, BytesN,
 };
 
 //
@@ -10,14 +10,18 @@ use soroban_sdk::{
 // ERROR DEFINITIONS
 // =====================
 //
-#[contracttype]
-#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
 pub enum PiNetworkError {
-    Unauthorized,
-    InvalidSource,
-    InvalidProvenance,
+    Unauthorized = 1,
+    InvalidSource = 2,
+    InvalidProvenance = 3,
+    AlreadyInitialized = 4,
 }
 
+const CONTRACT_VERSION: u32 = 1;
+
 //
 // =====================
 // SOURCE TYPE
@@ -51,7 +55,11 @@ impl PiNetworkContract {
         collateral: Address,
         oracle: Address,
         governance: Address,
-    ) {
+    ) -> Result<(), PiNetworkError> {
+        if env.storage().instance().has(&Symbol::new(&env, "admin")) {
+            return Err(PiNetworkError::AlreadyInitialized);
+        }
+
         admin.require_auth();
 
         env.storage().instance().set(
@@ -70,11 +78,68 @@ impl PiNetworkContract {
             &Symbol::new(&env, "governance"),
             &governance,
         );
+        env.storage().instance().set(
+            &Symbol::new(&env, "version"),
+            &CONTRACT_VERSION,
+        );
 
-        env.logger().log(
-            &Symbol::new(&env, "INIT"),
-            "Pi Network contract initialized",
+        env.events().publish(
+            (symbol_short!("init"), admin),
+            CONTRACT_VERSION,
         );
+
+        Ok(())
+    }
+
+    // -----------------
+    // VERSION & UPGRADE
+    // -----------------
+    pub fn version(env: Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&Symbol::new(&env, "version"))
+            .unwrap_or(0)
+    }
+
+    pub fn upgrade(
+        env: Env,
+        new_wasm_hash: BytesN<32>,
+    ) -> Result<(), PiNetworkError> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&Symbol::new(&env, "admin"))
+            .ok_or(PiNetworkError::Unauthorized)?;
+
+        admin.require_auth();
+
+        env.events().publish(
+            (symbol_short!("upgrade"),),
+            new_wasm_hash.clone(),
+        );
+
+        env.deployer().update_current_contract_wasm(new_wasm_hash);
+
+        Ok(())
+    }
+
+    pub fn migrate(env: Env) -> Result<u32, PiNetworkError> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&Symbol::new(&env, "admin"))
+            .ok_or(PiNetworkError::Unauthorized)?;
+
+        admin.require_auth();
+
+        if Self::version(env.clone()) < CONTRACT_VERSION {
+            env.storage().instance().set(
+                &Symbol::new(&env, "version"),
+                &CONTRACT_VERSION,
+            );
+        }
+
+        Ok(CONTRACT_VERSION)
     }
 
     // -----------------
@@ -83,7 +148,7 @@ impl PiNetworkContract {
     pub fn mint(
         env: Env,
         to: Address,
-        _amount: i128,
+        amount: i128,
         source: PiNetworkSource,
     ) -> Result<(), PiNetworkError> {
         let admin: Address = env
@@ -98,9 +163,13 @@ impl PiNetworkContract {
             .persistent()
             .set(&to, &source);
 
-        env.logger().log(
-            &Symbol::new(&env, "MINT"),
-            "Mint recorded with provenance",
+        env.events().publish(
+            (symbol_short!("mint"), to.clone()),
+            amount,
+        );
+        env.events().publish(
+            (symbol_short!("prov"), to),
+            source,
         );
 
         Ok(())
@@ -113,7 +182,7 @@ impl PiNetworkContract {
         env: Env,
         from: Address,
         to: Address,
-        _amount: i128,
+        amount: i128,
     ) -> Result<(), PiNetworkError> {
         from.require_auth();
 
@@ -135,13 +204,18 @@ impl PiNetworkContract {
         }
 
         // Propagate provenance
+        let source = source.unwrap();
         env.storage()
             .persistent()
-            .set(&to, &source.unwrap());
+            .set(&to, &source);
 
-        env.logger().log(
-            &Symbol::new(&env, "TRANSFER"),
-            "Transfer passed ZKP & provenance validation",
+        env.events().publish(
+            (symbol_short!("transfer"), from, to.clone()),
+            amount,
+        );
+        env.events().publish(
+            (symbol_short!("prov"), to),
+            source,
         );
 
         Ok(())
@@ -161,11 +235,6 @@ impl PiNetworkContract {
             return Err(PiNetworkError::InvalidSource);
         }
 
-        env.logger().log(
-            &Symbol::new(&env, "PEG"),
-            "Peg verified via oracle commitment",
-        );
-
         Ok(())
     }
 
@@ -186,9 +255,9 @@ impl PiNetworkContract {
             return Err(PiNetworkError::InvalidSource);
         }
 
-        env.logger().log(
-            &proposal,
-            "DAO vote recorded (Pi Network)",
+        env.events().publish(
+            (symbol_short!("gov"), symbol_short!("vote"), voter),
+            proposal,
         );
 
         Ok(())
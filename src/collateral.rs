@@ -0,0 +1,149 @@
+use soroban_sdk::{token, Env, Address};
+
+use crate::oracle::Oracle;
+use crate::storage::Storage;
+use crate::error::PiCoinError;
+
+/// Oracle pegs are quoted in collateral units per PI with 6 decimals,
+/// matching `PiCoinData.peg_value`.
+pub const PEG_DECIMALS: u32 = 6;
+pub const PEG_SCALE: i128 = 1_000_000;
+pub const BPS_DENOMINATOR: i128 = 10_000;
+pub const DEFAULT_COLLATERAL_RATIO_BPS: u32 = 15_000;
+
+pub struct Collateral;
+
+impl Collateral {
+    // ---------- PRICING ----------
+    /// Collateral needed to back `amount` PI at `peg` and the stored ratio.
+    pub fn required_for(
+        env: &Env,
+        amount: i128,
+        peg: i128,
+    ) -> Result<i128, PiCoinError> {
+        let ratio = Storage::get_collateral_ratio(env) as i128;
+
+        amount
+            .checked_mul(peg)
+            .and_then(|v| v.checked_mul(ratio))
+            .map(|v| v / (PEG_SCALE * BPS_DENOMINATOR))
+            .ok_or(PiCoinError::InsufficientCollateral)
+    }
+
+    // ---------- TOKEN MOVEMENT ----------
+    pub fn deposit(
+        env: &Env,
+        from: &Address,
+        amount: i128,
+    ) -> Result<(), PiCoinError> {
+        let collateral = Storage::get_collateral(env)
+            .ok_or(PiCoinError::NotInitialized)?;
+
+        token::Client::new(env, &collateral).transfer(
+            from,
+            env.current_contract_address(),
+            &amount,
+        );
+
+        Ok(())
+    }
+
+    pub fn release(
+        env: &Env,
+        to: &Address,
+        amount: i128,
+    ) -> Result<(), PiCoinError> {
+        let collateral = Storage::get_collateral(env)
+            .ok_or(PiCoinError::NotInitialized)?;
+
+        token::Client::new(env, &collateral).transfer(
+            &env.current_contract_address(),
+            to,
+            &amount,
+        );
+
+        Ok(())
+    }
+
+    // ---------- POSITIONS ----------
+    /// Pulls the collateral backing a fresh mint of `amount` from `owner`.
+    pub fn open(
+        env: &Env,
+        owner: &Address,
+        amount: i128,
+    ) -> Result<i128, PiCoinError> {
+        let peg = Oracle::fresh_peg(env)?;
+        let required = Self::required_for(env, amount, peg)?;
+
+        Self::deposit(env, owner, required)?;
+
+        let mut position = Storage::get_position(env, owner);
+        position.collateral += required;
+        position.debt += amount;
+        Storage::set_position(env, owner, &position);
+
+        Ok(required)
+    }
+
+    /// Closes `amount` of debt and returns the pro-rata share of collateral.
+    pub fn redeem(
+        env: &Env,
+        owner: &Address,
+        amount: i128,
+    ) -> Result<i128, PiCoinError> {
+        let mut position = Storage::get_position(env, owner);
+
+        if amount > position.debt {
+            return Err(PiCoinError::InsufficientCollateral);
+        }
+
+        if amount == 0 {
+            return Ok(0);
+        }
+
+        let released = position.collateral * amount / position.debt;
+
+        position.collateral -= released;
+        position.debt -= amount;
+        Storage::set_position(env, owner, &position);
+
+        Self::release(env, owner, released)?;
+
+        Ok(released)
+    }
+
+    pub fn top_up(
+        env: &Env,
+        owner: &Address,
+        amount: i128,
+    ) -> Result<(), PiCoinError> {
+        Self::deposit(env, owner, amount)?;
+
+        let mut position = Storage::get_position(env, owner);
+        position.collateral += amount;
+        Storage::set_position(env, owner, &position);
+
+        Ok(())
+    }
+
+    /// Releases collateral above what the remaining debt requires.
+    pub fn withdraw(
+        env: &Env,
+        owner: &Address,
+        amount: i128,
+    ) -> Result<(), PiCoinError> {
+        let mut position = Storage::get_position(env, owner);
+
+        let peg = Oracle::fresh_peg(env)?;
+        let required = Self::required_for(env, position.debt, peg)?;
+
+        if position.collateral - amount < required {
+            return Err(PiCoinError::InsufficientCollateral);
+        }
+
+        position.collateral -= amount;
+        Storage::set_position(env, owner, &position);
+
+        Self::release(env, owner, amount)
+    }
+}
@@ -1,9 +1,40 @@
-use soroban_sdk::contracttype;
+use soroban_sdk::contracterror;
 
-#[contracttype]
-#[derive(Clone, Debug, PartialEq)]
+/// Codes are part of the public API: never renumber a variant, only append.
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
 pub enum PiCoinError {
-    InsufficientCollateral,
-    InvalidSource,
-    InvalidProvenance,
+    InsufficientCollateral = 1,
+    InvalidSource = 2,
+    InvalidProvenance = 3,
+    NegativeAmount = 4,
+    InsufficientBalance = 5,
+    InsufficientAllowance = 6,
+    InvalidExpiration = 7,
+    SupplyCapExceeded = 8,
+    Unauthorized = 9,
+    AlreadyInitialized = 10,
+    NotInitialized = 11,
+    InvalidCollateralRatio = 12,
+    OracleNotSet = 13,
+    OracleStale = 14,
+    InvalidPegConfig = 15,
+    VerifyingKeyNotSet = 16,
+    MalformedProof = 17,
+    InvalidProof = 18,
+    InvalidTtlConfig = 19,
+    ProposalNotFound = 20,
+    VotingClosed = 21,
+    VotingOpen = 22,
+    AlreadyVoted = 23,
+    ProposalFinalized = 24,
+    InvalidGovernanceConfig = 25,
+    InvalidSourceWeight = 26,
+    NoVotingPower = 27,
+    InvalidOracleDecimals = 28,
+    VotingHistoryUnavailable = 29,
+    ProofNotBound = 30,
+    ProofReplayed = 31,
+    OracleUnavailable = 32,
 }
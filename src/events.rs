@@ -0,0 +1,138 @@
+//! Contract events. Token events follow the SEP-41 layout so wallets and
+//! indexers can track PI like any other Soroban token; the full schema for
+//! every contract in this repo lives in `docs/events.md`.
+
+use soroban_sdk::{
+    symbol_short, Env, Address, BytesN, IntoVal, Symbol, Val, Vec,
+};
+
+use crate::storage::{ProposalStatus, ProvenanceLot, Role, VoteChoice};
+
+pub struct Events;
+
+impl Events {
+    // ---------- LIFECYCLE ----------
+    pub fn init(env: &Env, admin: &Address, version: u32) {
+        env.events()
+            .publish((symbol_short!("init"), admin.clone()), version);
+    }
+
+    pub fn upgrade(env: &Env, new_wasm_hash: &BytesN<32>) {
+        env.events()
+            .publish((symbol_short!("upgrade"),), new_wasm_hash.clone());
+    }
+
+    pub fn migrate(env: &Env, from_version: u32, to_version: u32) {
+        env.events()
+            .publish((symbol_short!("migrate"),), (from_version, to_version));
+    }
+
+    // ---------- TOKEN (SEP-41) ----------
+    pub fn mint(env: &Env, to: &Address, amount: i128) {
+        env.events()
+            .publish((symbol_short!("mint"), to.clone()), amount);
+    }
+
+    pub fn transfer(env: &Env, from: &Address, to: &Address, amount: i128) {
+        env.events().publish(
+            (symbol_short!("transfer"), from.clone(), to.clone()),
+            amount,
+        );
+    }
+
+    pub fn approve(
+        env: &Env,
+        from: &Address,
+        spender: &Address,
+        amount: i128,
+        expiration_ledger: u32,
+    ) {
+        env.events().publish(
+            (symbol_short!("approve"), from.clone(), spender.clone()),
+            (amount, expiration_ledger),
+        );
+    }
+
+    pub fn burn(env: &Env, from: &Address, amount: i128) {
+        env.events()
+            .publish((symbol_short!("burn"), from.clone()), amount);
+    }
+
+    // ---------- PROVENANCE ----------
+    pub fn provenance(env: &Env, addr: &Address, lots: &Vec<ProvenanceLot>) {
+        env.events()
+            .publish((symbol_short!("prov"), addr.clone()), lots.clone());
+    }
+
+    // ---------- ROLES & CONFIG ----------
+    pub fn role_proposed(env: &Env, role: Role, new_holder: &Address) {
+        env.events().publish(
+            (symbol_short!("role"), symbol_short!("proposed"), role),
+            new_holder.clone(),
+        );
+    }
+
+    pub fn role_accepted(env: &Env, role: Role, holder: &Address) {
+        env.events().publish(
+            (symbol_short!("role"), symbol_short!("accepted"), role),
+            holder.clone(),
+        );
+    }
+
+    pub fn config<V>(env: &Env, key: Symbol, value: V)
+    where
+        V: IntoVal<Env, Val>,
+    {
+        env.events().publish((symbol_short!("config"), key), value);
+    }
+
+    // ---------- COLLATERAL ----------
+    pub fn deposit(env: &Env, owner: &Address, amount: i128) {
+        env.events()
+            .publish((symbol_short!("deposit"), owner.clone()), amount);
+    }
+
+    pub fn withdraw(env: &Env, owner: &Address, amount: i128) {
+        env.events()
+            .publish((symbol_short!("withdraw"), owner.clone()), amount);
+    }
+
+    pub fn redeem(env: &Env, owner: &Address, amount: i128, released: i128) {
+        env.events().publish(
+            (symbol_short!("redeem"), owner.clone()),
+            (amount, released),
+        );
+    }
+
+    // ---------- GOVERNANCE ----------
+    pub fn proposal_created(
+        env: &Env,
+        id: u32,
+        proposer: &Address,
+        description_hash: &BytesN<32>,
+    ) {
+        env.events().publish(
+            (symbol_short!("gov"), symbol_short!("proposal"), id),
+            (proposer.clone(), description_hash.clone()),
+        );
+    }
+
+    pub fn vote(
+        env: &Env,
+        voter: &Address,
+        proposal_id: u32,
+        choice: VoteChoice,
+    ) {
+        env.events().publish(
+            (symbol_short!("gov"), symbol_short!("vote"), voter.clone()),
+            (proposal_id, choice),
+        );
+    }
+
+    pub fn proposal_finalized(env: &Env, id: u32, status: ProposalStatus) {
+        env.events().publish(
+            (symbol_short!("gov"), symbol_short!("finalize"), id),
+            status,
+        );
+    }
+}
@@ -1,24 +1,239 @@
-use soroban_sdk::{Env, Address, Symbol};
+use soroban_sdk::{Env, Address, BytesN, Map, Vec};
 
-use crate::storage::Storage;
+use crate::storage::{
+    PiCoinSource, Proposal, ProposalStatus, ProvenanceLot, Storage,
+    VoteChoice, VotingCheckpoint,
+};
+use crate::collateral::BPS_DENOMINATOR;
 use crate::error::PiCoinError;
+use crate::events::Events;
+
+pub const DEFAULT_VOTING_PERIOD_SECS: u64 = 3 * 24 * 60 * 60;
+pub const DEFAULT_QUORUM: i128 = 1;
+// Simple majority of the decisive votes.
+pub const DEFAULT_THRESHOLD_BPS: u32 = 5_000;
+
+// Source weights are multipliers in bps: 10_000 counts a token once.
+pub const DEFAULT_SOURCE_WEIGHT_BPS: u32 = 10_000;
+pub const MAX_SOURCE_WEIGHT_BPS: u32 = 100_000;
 
 pub struct Governance;
 
 impl Governance {
+    pub fn propose(
+        env: &Env,
+        proposer: Address,
+        description_hash: BytesN<32>,
+    ) -> Result<u32, PiCoinError> {
+        proposer.require_auth();
+
+        // Must have valid provenance
+        Storage::get_provenance(env, &proposer)?;
+
+        let config = Storage::get_governance_config(env);
+        let now = env.ledger().timestamp();
+
+        let proposal = Proposal {
+            id: Storage::next_proposal_id(env),
+            proposer: proposer.clone(),
+            description_hash: description_hash.clone(),
+            start_time: now,
+            end_time: now + config.voting_period_secs,
+            snapshot_ledger: env.ledger().sequence(),
+            quorum: config.quorum,
+            threshold_bps: config.threshold_bps,
+            source_weights: Self::source_weights(env),
+            votes_for: 0,
+            votes_against: 0,
+            votes_abstain: 0,
+            status: ProposalStatus::Active,
+        };
+
+        Storage::set_proposal(env, &proposal);
+        Events::proposal_created(
+            env,
+            proposal.id,
+            &proposer,
+            &description_hash,
+        );
+
+        Ok(proposal.id)
+    }
+
     pub fn vote(
         env: &Env,
         voter: Address,
-        proposal: Symbol,
+        proposal_id: u32,
+        choice: VoteChoice,
     ) -> Result<(), PiCoinError> {
-        // Must have valid provenance
-        Storage::get_provenance(env, &voter)?;
+        voter.require_auth();
 
-        env.logger().log(
-            &proposal,
-            "DAO vote recorded with PQ-ready symbolic intent",
-        );
+        let mut proposal = Storage::get_proposal(env, proposal_id)
+            .ok_or(PiCoinError::ProposalNotFound)?;
+
+        if proposal.status != ProposalStatus::Active
+            || env.ledger().timestamp() >= proposal.end_time
+        {
+            return Err(PiCoinError::VotingClosed);
+        }
+
+        if Storage::get_vote(env, proposal_id, &voter).is_some() {
+            return Err(PiCoinError::AlreadyVoted);
+        }
+
+        let weight = Self::voting_power(env, &voter, &proposal)?;
+        if weight == 0 {
+            return Err(PiCoinError::NoVotingPower);
+        }
+
+        match choice {
+            VoteChoice::For => proposal.votes_for += weight,
+            VoteChoice::Against => proposal.votes_against += weight,
+            VoteChoice::Abstain => proposal.votes_abstain += weight,
+        }
+
+        Storage::set_vote(env, proposal_id, &voter, choice);
+        Storage::set_proposal(env, &proposal);
+
+        Events::vote(env, &voter, proposal_id, choice);
 
         Ok(())
     }
+
+    /// Closes a proposal once its window has ended. Callable by anyone.
+    pub fn finalize(
+        env: &Env,
+        proposal_id: u32,
+    ) -> Result<ProposalStatus, PiCoinError> {
+        let mut proposal = Storage::get_proposal(env, proposal_id)
+            .ok_or(PiCoinError::ProposalNotFound)?;
+
+        if proposal.status != ProposalStatus::Active {
+            return Err(PiCoinError::ProposalFinalized);
+        }
+
+        if env.ledger().timestamp() < proposal.end_time {
+            return Err(PiCoinError::VotingOpen);
+        }
+
+        proposal.status = Self::outcome(&proposal);
+        Storage::set_proposal(env, &proposal);
+
+        Events::proposal_finalized(env, proposal_id, proposal.status);
+
+        Ok(proposal.status)
+    }
+
+    /// Weighted balance of `voter` at the proposal snapshot.
+    pub fn voting_power(
+        env: &Env,
+        voter: &Address,
+        proposal: &Proposal,
+    ) -> Result<i128, PiCoinError> {
+        let lots = Self::lots_before(env, voter, proposal.snapshot_ledger)?;
+
+        Ok(lots
+            .iter()
+            .map(|lot| {
+                let weight = proposal
+                    .source_weights
+                    .get(lot.source)
+                    .unwrap_or(0);
+
+                lot.amount * weight as i128 / BPS_DENOMINATOR
+            })
+            .sum())
+    }
+
+    pub fn source_weights(env: &Env) -> Map<PiCoinSource, u32> {
+        let mut weights = Map::new(env);
+
+        for source in [
+            PiCoinSource::Mining,
+            PiCoinSource::Rewards,
+            PiCoinSource::P2P,
+        ] {
+            let weight = Storage::get_source_weight(env, &source);
+            weights.set(source, weight);
+        }
+
+        weights
+    }
+
+    /// Records `lots` as the holder's position at the current ledger. Several
+    /// changes within one ledger collapse into a single checkpoint.
+    pub fn checkpoint(env: &Env, owner: &Address, lots: &Vec<ProvenanceLot>) {
+        let ledger = env.ledger().sequence();
+        let count = Storage::get_checkpoint_count(env, owner);
+
+        let checkpoint = VotingCheckpoint {
+            ledger,
+            lots: lots.clone(),
+        };
+
+        if count > 0 {
+            if let Some(last) = Storage::get_checkpoint(env, owner, count - 1) {
+                if last.ledger == ledger {
+                    Storage::set_checkpoint(env, owner, count - 1, &checkpoint);
+                    return;
+                }
+            }
+        }
+
+        Storage::set_checkpoint(env, owner, count, &checkpoint);
+        Storage::set_checkpoint_count(env, owner, count + 1);
+    }
+
+    // Latest checkpoint strictly before `ledger`, so balances moved in the
+    // proposal's own ledger never count towards it. Fails if a checkpoint
+    // has been archived rather than reading it as zero power. Only the
+    // checkpoint returned is extended, not every probe.
+    fn lots_before(
+        env: &Env,
+        owner: &Address,
+        ledger: u32,
+    ) -> Result<Vec<ProvenanceLot>, PiCoinError> {
+        let mut low = 0;
+        let mut high = Storage::get_checkpoint_count(env, owner);
+
+        while low < high {
+            let mid = (low + high) / 2;
+            let checkpoint = Storage::peek_checkpoint(env, owner, mid)
+                .ok_or(PiCoinError::VotingHistoryUnavailable)?;
+
+            if checkpoint.ledger < ledger {
+                low = mid + 1;
+            } else {
+                high = mid;
+            }
+        }
+
+        if low == 0 {
+            return Ok(Vec::new(env));
+        }
+
+        Storage::get_checkpoint(env, owner, low - 1)
+            .map(|checkpoint| checkpoint.lots)
+            .ok_or(PiCoinError::VotingHistoryUnavailable)
+    }
+
+    fn outcome(proposal: &Proposal) -> ProposalStatus {
+        let cast = proposal.votes_for
+            + proposal.votes_against
+            + proposal.votes_abstain;
+
+        if cast < proposal.quorum {
+            return ProposalStatus::QuorumNotMet;
+        }
+
+        let decisive = proposal.votes_for + proposal.votes_against;
+
+        if proposal.votes_for * BPS_DENOMINATOR
+            > decisive * proposal.threshold_bps as i128
+        {
+            ProposalStatus::Passed
+        } else {
+            ProposalStatus::Rejected
+        }
+    }
 }
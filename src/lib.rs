@@ -1,16 +1,41 @@
 #![no_std]
 
 use soroban_sdk::{
-    contract, contractimpl, Env, Address, Symbol, Bytes,
+    contract, contractimpl, symbol_short, Env, Address, Bytes, BytesN,
+    Map, String, Vec, crypto::bls12_381::Fr,
 };
 
 mod storage;
 mod governance;
 mod error;
+mod token;
+mod roles;
+mod oracle;
+mod collateral;
+mod zkp;
+mod provenance;
+mod events;
 
-use storage::{PiCoinData, PiCoinSource, Storage};
-use governance::Governance;
+#[cfg(test)]
+mod test;
+
+use storage::{
+    AttributionMode, CollateralPosition, GovernanceConfig, PegConfig,
+    PegStatus, PiCoinData, PiCoinSource, Proposal, ProposalStatus, Role,
+    Storage, TtlConfig, VoteChoice,
+};
+use governance::{Governance, MAX_SOURCE_WEIGHT_BPS};
 use error::PiCoinError;
+use token::Token;
+use roles::Roles;
+use collateral::{Collateral, BPS_DENOMINATOR};
+use oracle::Oracle;
+use zkp::{Groth16Proof, VerifyingKey, Zkp};
+use provenance::Provenance;
+use events::Events;
+
+/// Bumped whenever a release changes the stored state layout.
+pub const CONTRACT_VERSION: u32 = 1;
 
 #[contract]
 pub struct PiCoinContract;
@@ -25,68 +50,445 @@ impl PiCoinContract {
         oracle: Address,
         governance: Address,
     ) -> Result<(), PiCoinError> {
+        if Storage::has_data(&env) {
+            return Err(PiCoinError::AlreadyInitialized);
+        }
+
+        admin.require_auth();
+
         let hash = env.crypto().sha256(
             &Bytes::from_slice(&env, b"PiCoin-Core-Protocol-Identifier"),
         );
 
         let data = PiCoinData {
             total_supply: 100_000_000_000,
+            circulating_supply: 0,
             peg_value: 314_159_000_000,
-            anti_fraud_hash: hash,
+            anti_fraud_hash: hash.into(),
         };
 
         Storage::set_data(&env, &data);
+        Storage::set_role(&env, Role::Admin, &admin);
+        Storage::set_role(&env, Role::Governance, &governance);
+        Storage::set_collateral(&env, &collateral);
+        Storage::set_oracle(&env, &oracle);
+        Storage::set_version(&env, CONTRACT_VERSION);
 
-        env.logger().log(
-            &Symbol::new(&env, "INIT"),
-            "Protocol initialized with PQ-aware commitments",
-        );
+        Events::init(&env, &admin, CONTRACT_VERSION);
 
         Ok(())
     }
 
+    // ---------- UPGRADE ----------
+    pub fn version(env: Env) -> u32 {
+        Storage::get_version(&env)
+    }
+
+    pub fn upgrade(
+        env: Env,
+        new_wasm_hash: BytesN<32>,
+    ) -> Result<(), PiCoinError> {
+        Roles::require(&env, Role::Admin)?;
+
+        Events::upgrade(&env, &new_wasm_hash);
+        env.deployer().update_current_contract_wasm(new_wasm_hash);
+
+        Ok(())
+    }
+
+    /// Runs state migrations from the stored version up to
+    /// `CONTRACT_VERSION`. Called by the admin right after `upgrade`.
+    pub fn migrate(env: Env) -> Result<u32, PiCoinError> {
+        Roles::require(&env, Role::Admin)?;
+
+        let stored = Storage::get_version(&env);
+
+        if stored < CONTRACT_VERSION {
+            // Per-version state migrations go here, oldest first.
+            Storage::set_version(&env, CONTRACT_VERSION);
+
+            Events::migrate(&env, stored, CONTRACT_VERSION);
+        }
+
+        Ok(CONTRACT_VERSION)
+    }
+
     // ---------- MINT ----------
+    // Requires admin auth for issuance and `to` auth for the collateral pull.
     pub fn mint(
         env: Env,
         to: Address,
         amount: i128,
         source: PiCoinSource,
     ) -> Result<(), PiCoinError> {
+        Roles::require(&env, Role::Admin)?;
+        to.require_auth();
+        Token::check_amount(amount)?;
+
         if source == PiCoinSource::Invalid {
             return Err(PiCoinError::InvalidSource);
         }
 
-        if amount > 100_000_000 {
-            return Err(PiCoinError::InsufficientCollateral);
+        Token::increase_supply(&env, amount)?;
+        Collateral::open(&env, &to, amount)?;
+        Token::receive_balance(&env, &to, amount);
+        Provenance::credit(&env, &to, &source, amount);
+
+        Events::mint(&env, &to, amount);
+
+        Ok(())
+    }
+
+    // ---------- TOKEN METADATA (SEP-41) ----------
+    pub fn decimals(_env: Env) -> u32 {
+        token::DECIMALS
+    }
+
+    pub fn name(env: Env) -> String {
+        String::from_str(&env, token::NAME)
+    }
+
+    pub fn symbol(env: Env) -> String {
+        String::from_str(&env, token::SYMBOL)
+    }
+
+    // ---------- ROLES ----------
+    pub fn transfer_role(
+        env: Env,
+        role: Role,
+        new_holder: Address,
+    ) -> Result<(), PiCoinError> {
+        Roles::propose(&env, role, &new_holder)?;
+
+        Events::role_proposed(&env, role, &new_holder);
+
+        Ok(())
+    }
+
+    pub fn accept_role(env: Env, role: Role) -> Result<(), PiCoinError> {
+        let holder = Roles::accept(&env, role)?;
+
+        Events::role_accepted(&env, role, &holder);
+
+        Ok(())
+    }
+
+    pub fn get_role(env: Env, role: Role) -> Option<Address> {
+        Storage::get_role(&env, role)
+    }
+
+    // ---------- PARAMETERS (ADMIN ONLY) ----------
+    pub fn set_zkp_base(env: Env, value: Bytes) -> Result<(), PiCoinError> {
+        Roles::require(&env, Role::Admin)?;
+
+        Storage::set_zkp_base(&env, &value);
+        Events::config(&env, symbol_short!("zkp_base"), value);
+
+        Ok(())
+    }
+
+    pub fn set_verifying_key(
+        env: Env,
+        vk: VerifyingKey,
+    ) -> Result<(), PiCoinError> {
+        Roles::require(&env, Role::Admin)?;
+
+        if vk.ic.is_empty() {
+            return Err(PiCoinError::MalformedProof);
         }
 
-        Storage::set_provenance(&env, &to, &source);
+        Storage::set_verifying_key(&env, &vk);
+        Events::config(&env, symbol_short!("zkp_vk"), vk.ic.len());
 
-        env.logger().log(
-            &Symbol::new(&env, "MINT"),
-            "Minted with valid source & PQ-aware provenance",
-        );
+        Ok(())
+    }
+
+    pub fn set_collateral(
+        env: Env,
+        collateral: Address,
+    ) -> Result<(), PiCoinError> {
+        Roles::require(&env, Role::Admin)?;
+
+        Storage::set_collateral(&env, &collateral);
+        Events::config(&env, symbol_short!("collatrl"), collateral);
+
+        Ok(())
+    }
+
+    pub fn set_oracle(env: Env, oracle: Address) -> Result<(), PiCoinError> {
+        Roles::require(&env, Role::Admin)?;
+
+        Storage::set_oracle(&env, &oracle);
+        Events::config(&env, symbol_short!("oracle"), oracle);
+
+        Ok(())
+    }
+
+    // ---------- STATE ARCHIVAL ----------
+    pub fn ttl_config(env: Env) -> TtlConfig {
+        Storage::get_ttl_config(&env)
+    }
+
+    pub fn set_ttl_config(
+        env: Env,
+        config: TtlConfig,
+    ) -> Result<(), PiCoinError> {
+        Roles::require(&env, Role::Admin)?;
+
+        let max_ttl = env.storage().max_ttl();
+        if config.instance_threshold >= config.instance_extend_to
+            || config.account_threshold >= config.account_extend_to
+            || config.instance_extend_to > max_ttl
+            || config.account_extend_to > max_ttl
+        {
+            return Err(PiCoinError::InvalidTtlConfig);
+        }
+
+        Storage::set_ttl_config(&env, &config);
+        Events::config(&env, symbol_short!("ttl_cfg"), config);
+
+        Ok(())
+    }
+
+    /// Extends the TTL of balances, provenance and positions for `accounts`
+    /// so dormant holders are not archived.
+    pub fn bump_accounts(
+        env: Env,
+        accounts: Vec<Address>,
+    ) -> Result<(), PiCoinError> {
+        Roles::require(&env, Role::Admin)?;
+
+        Storage::bump_instance(&env);
+        for account in accounts.iter() {
+            Storage::bump_account_entries(&env, &account);
+        }
 
         Ok(())
     }
 
+    // ---------- SUPPLY ----------
+    pub fn circulating_supply(env: Env) -> i128 {
+        Storage::get_data(&env).circulating_supply
+    }
+
+    pub fn max_supply(env: Env) -> i128 {
+        Storage::get_data(&env).total_supply
+    }
+
+    // ---------- BALANCE & ALLOWANCE (SEP-41) ----------
+    pub fn balance(env: Env, id: Address) -> i128 {
+        Storage::get_balance(&env, &id)
+    }
+
+    pub fn allowance(env: Env, from: Address, spender: Address) -> i128 {
+        Storage::get_allowance(&env, &from, &spender).amount
+    }
+
+    pub fn approve(
+        env: Env,
+        from: Address,
+        spender: Address,
+        amount: i128,
+        expiration_ledger: u32,
+    ) -> Result<(), PiCoinError> {
+        from.require_auth();
+        Token::check_amount(amount)?;
+
+        Storage::set_allowance(&env, &from, &spender, amount, expiration_ledger)?;
+
+        Events::approve(&env, &from, &spender, amount, expiration_ledger);
+
+        Ok(())
+    }
+
+    // ---------- COLLATERAL POSITIONS ----------
+    pub fn position(env: Env, owner: Address) -> CollateralPosition {
+        Storage::get_position(&env, &owner)
+    }
+
+    pub fn collateral_ratio(env: Env) -> u32 {
+        Storage::get_collateral_ratio(&env)
+    }
+
+    pub fn set_collateral_ratio(
+        env: Env,
+        ratio_bps: u32,
+    ) -> Result<(), PiCoinError> {
+        Roles::require(&env, Role::Admin)?;
+
+        if (ratio_bps as i128) < BPS_DENOMINATOR {
+            return Err(PiCoinError::InvalidCollateralRatio);
+        }
+
+        Storage::set_collateral_ratio(&env, ratio_bps);
+        Events::config(&env, symbol_short!("coll_rat"), ratio_bps);
+
+        Ok(())
+    }
+
+    pub fn deposit_collateral(
+        env: Env,
+        from: Address,
+        amount: i128,
+    ) -> Result<(), PiCoinError> {
+        from.require_auth();
+        Token::check_amount(amount)?;
+
+        Collateral::top_up(&env, &from, amount)?;
+
+        Events::deposit(&env, &from, amount);
+
+        Ok(())
+    }
+
+    pub fn withdraw_collateral(
+        env: Env,
+        from: Address,
+        amount: i128,
+    ) -> Result<(), PiCoinError> {
+        from.require_auth();
+        Token::check_amount(amount)?;
+
+        Collateral::withdraw(&env, &from, amount)?;
+
+        Events::withdraw(&env, &from, amount);
+
+        Ok(())
+    }
+
+    /// Burns `amount` PI against the caller's position and returns the
+    /// matching share of collateral. Returns the collateral released.
+    pub fn redeem(
+        env: Env,
+        from: Address,
+        amount: i128,
+    ) -> Result<i128, PiCoinError> {
+        from.require_auth();
+        Token::check_amount(amount)?;
+
+        Token::spend_balance(&env, &from, amount)?;
+        Token::decrease_supply(&env, amount);
+        let released = Collateral::redeem(&env, &from, amount)?;
+
+        Events::burn(&env, &from, amount);
+        Events::redeem(&env, &from, amount, released);
+
+        Ok(released)
+    }
+
     // ---------- TRANSFER ----------
     pub fn transfer(
         env: Env,
         from: Address,
         to: Address,
-        _amount: i128,
+        amount: i128,
     ) -> Result<(), PiCoinError> {
-        if !Storage::has_zkp_base(&env) {
-            return Err(PiCoinError::InvalidProvenance);
-        }
+        from.require_auth();
+        Token::check_amount(amount)?;
+        Token::check_transfer_gate(&env, &from)?;
+
+        Token::move_balance(&env, &from, &to, amount)?;
 
+        Events::transfer(&env, &from, &to, amount);
+
+        Ok(())
+    }
+
+    /// Transfer gated by a Groth16 proof checked against the registered
+    /// verifying key instead of the static `zkp_base` commitment. The last
+    /// public input must be `transfer_binding(from, to, amount)` and the one
+    /// before it is a nullifier, accepted once.
+    pub fn transfer_with_proof(
+        env: Env,
+        from: Address,
+        to: Address,
+        amount: i128,
+        proof: Groth16Proof,
+        public_inputs: Vec<Fr>,
+    ) -> Result<(), PiCoinError> {
+        from.require_auth();
+        Token::check_amount(amount)?;
         Storage::get_provenance(&env, &from)?;
 
-        env.logger().log(
-            &Symbol::new(&env, "TRANSFER"),
-            "Transfer passed ZKP gate and provenance validation",
-        );
+        let binding = Zkp::transfer_binding(&env, &from, &to, amount);
+        if public_inputs.last() != Some(binding) {
+            return Err(PiCoinError::ProofNotBound);
+        }
+
+        let nullifier = Zkp::nullifier(&public_inputs)
+            .ok_or(PiCoinError::MalformedProof)?;
+        if Storage::is_nullifier_spent(&env, &nullifier) {
+            return Err(PiCoinError::ProofReplayed);
+        }
+
+        Zkp::verify(&env, &proof, &public_inputs)?;
+        Storage::set_nullifier_spent(&env, &nullifier);
+        Token::move_balance(&env, &from, &to, amount)?;
+
+        Events::transfer(&env, &from, &to, amount);
+
+        Ok(())
+    }
+
+    pub fn transfer_binding(
+        env: Env,
+        from: Address,
+        to: Address,
+        amount: i128,
+    ) -> Fr {
+        Zkp::transfer_binding(&env, &from, &to, amount)
+    }
+
+    pub fn transfer_from(
+        env: Env,
+        spender: Address,
+        from: Address,
+        to: Address,
+        amount: i128,
+    ) -> Result<(), PiCoinError> {
+        spender.require_auth();
+        Token::check_amount(amount)?;
+        Token::check_transfer_gate(&env, &from)?;
+
+        Token::spend_allowance(&env, &from, &spender, amount)?;
+        Token::move_balance(&env, &from, &to, amount)?;
+
+        Events::transfer(&env, &from, &to, amount);
+
+        Ok(())
+    }
+
+    // ---------- BURN ----------
+    pub fn burn(
+        env: Env,
+        from: Address,
+        amount: i128,
+    ) -> Result<(), PiCoinError> {
+        from.require_auth();
+        Token::check_amount(amount)?;
+
+        Token::spend_balance(&env, &from, amount)?;
+        Token::decrease_supply(&env, amount);
+
+        Events::burn(&env, &from, amount);
+
+        Ok(())
+    }
+
+    pub fn burn_from(
+        env: Env,
+        spender: Address,
+        from: Address,
+        amount: i128,
+    ) -> Result<(), PiCoinError> {
+        spender.require_auth();
+        Token::check_amount(amount)?;
+
+        Token::spend_allowance(&env, &from, &spender, amount)?;
+        Token::spend_balance(&env, &from, amount)?;
+        Token::decrease_supply(&env, amount);
+
+        Events::burn(&env, &from, amount);
 
         Ok(())
     }
@@ -95,24 +497,157 @@ impl PiCoinContract {
     pub fn verify_peg(
         env: Env,
         holder: Address,
-    ) -> Result<(), PiCoinError> {
+    ) -> Result<PegStatus, PiCoinError> {
         Storage::get_provenance(&env, &holder)?;
 
-        env.logger().log(
-            &Symbol::new(&env, "PEG"),
-            "Peg verified via oracle commitment (PQ-ready)",
-        );
+        Oracle::status(&env)
+    }
+
+    pub fn peg_config(env: Env) -> PegConfig {
+        Storage::get_peg_config(&env)
+    }
+
+    pub fn set_peg_config(
+        env: Env,
+        config: PegConfig,
+    ) -> Result<(), PiCoinError> {
+        Roles::require(&env, Role::Admin)?;
+
+        if config.tolerance_bps as i128 > BPS_DENOMINATOR
+            || config.max_age_secs == 0
+        {
+            return Err(PiCoinError::InvalidPegConfig);
+        }
+
+        Storage::set_peg_config(&env, &config);
+        Events::config(&env, symbol_short!("peg_cfg"), config);
 
         Ok(())
     }
 
     // ---------- GOVERNANCE ----------
+    pub fn create_proposal(
+        env: Env,
+        proposer: Address,
+        description_hash: BytesN<32>,
+    ) -> Result<u32, PiCoinError> {
+        Governance::propose(&env, proposer, description_hash)
+    }
+
     pub fn governance_vote(
         env: Env,
         voter: Address,
-        proposal: Symbol,
+        proposal_id: u32,
+        choice: VoteChoice,
+    ) -> Result<(), PiCoinError> {
+        Governance::vote(&env, voter, proposal_id, choice)
+    }
+
+    pub fn finalize_proposal(
+        env: Env,
+        proposal_id: u32,
+    ) -> Result<ProposalStatus, PiCoinError> {
+        Governance::finalize(&env, proposal_id)
+    }
+
+    pub fn get_proposal(
+        env: Env,
+        proposal_id: u32,
+    ) -> Result<Proposal, PiCoinError> {
+        Storage::get_proposal(&env, proposal_id)
+            .ok_or(PiCoinError::ProposalNotFound)
+    }
+
+    pub fn get_vote(
+        env: Env,
+        proposal_id: u32,
+        voter: Address,
+    ) -> Option<VoteChoice> {
+        Storage::get_vote(&env, proposal_id, &voter)
+    }
+
+    pub fn voting_power(
+        env: Env,
+        voter: Address,
+        proposal_id: u32,
+    ) -> Result<i128, PiCoinError> {
+        let proposal = Storage::get_proposal(&env, proposal_id)
+            .ok_or(PiCoinError::ProposalNotFound)?;
+
+        Governance::voting_power(&env, &voter, &proposal)
+    }
+
+    pub fn source_weight(env: Env, source: PiCoinSource) -> u32 {
+        Storage::get_source_weight(&env, &source)
+    }
+
+    /// Sets the voting multiplier for `source` in bps (10_000 = 1x).
+    pub fn set_source_weight(
+        env: Env,
+        source: PiCoinSource,
+        weight_bps: u32,
     ) -> Result<(), PiCoinError> {
-        Governance::vote(&env, voter, proposal)
+        Roles::require(&env, Role::Governance)?;
+
+        if source == PiCoinSource::Invalid
+            || weight_bps > MAX_SOURCE_WEIGHT_BPS
+        {
+            return Err(PiCoinError::InvalidSourceWeight);
+        }
+
+        Storage::set_source_weight(&env, &source, weight_bps);
+        Events::config(&env, symbol_short!("src_wt"), (source, weight_bps));
+
+        Ok(())
+    }
+
+    pub fn governance_config(env: Env) -> GovernanceConfig {
+        Storage::get_governance_config(&env)
+    }
+
+    /// Only affects proposals created after the change.
+    pub fn set_governance_config(
+        env: Env,
+        config: GovernanceConfig,
+    ) -> Result<(), PiCoinError> {
+        Roles::require(&env, Role::Governance)?;
+
+        if config.voting_period_secs == 0
+            || config.quorum <= 0
+            || (config.threshold_bps as i128) < BPS_DENOMINATOR / 2
+            || config.threshold_bps as i128 >= BPS_DENOMINATOR
+        {
+            return Err(PiCoinError::InvalidGovernanceConfig);
+        }
+
+        Storage::set_governance_config(&env, &config);
+        Events::config(&env, symbol_short!("gov_cfg"), config);
+
+        Ok(())
+    }
+
+    // ---------- PROVENANCE ----------
+    pub fn provenance_breakdown(
+        env: Env,
+        addr: Address,
+    ) -> Map<PiCoinSource, i128> {
+        Provenance::breakdown(&env, &addr)
+    }
+
+    pub fn attribution_mode(env: Env) -> AttributionMode {
+        Storage::get_attribution_mode(&env)
+    }
+
+    pub fn set_attribution_mode(
+        env: Env,
+        mode: AttributionMode,
+    ) -> Result<(), PiCoinError> {
+        Roles::require(&env, Role::Admin)?;
+
+        Storage::set_attribution_mode(&env, mode);
+        Events::config(&env, symbol_short!("attrib"), mode);
+
+        Ok(())
     }
 
     // ---------- ECOSYSTEM ENTRY ----------
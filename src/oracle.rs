@@ -0,0 +1,104 @@
+use soroban_sdk::{contractclient, contracttype, Env};
+
+use crate::collateral::{BPS_DENOMINATOR, PEG_DECIMALS};
+use crate::storage::{PegConfig, PegStatus, Storage};
+use crate::error::PiCoinError;
+
+pub const DEFAULT_PEG_TOLERANCE_BPS: u32 = 100;
+pub const DEFAULT_ORACLE_MAX_AGE_SECS: u64 = 3_600;
+
+/// Mirrors `RoundData` returned by `PiPegOracleContract::latest_round_data`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RoundData {
+    pub round_id: u32,
+    pub answer: i128,
+    pub started_at: u64,
+    pub updated_at: u64,
+    pub answered_in_round: u32,
+}
+
+/// Subset of the `PiPegOracleContract` interface consumed by PiCoin.
+#[allow(dead_code)]
+#[contractclient(name = "PegOracleClient")]
+pub trait PegOracleInterface {
+    fn decimals(env: Env) -> u32;
+    fn latest_round_data(env: Env) -> RoundData;
+}
+
+pub struct Oracle;
+
+impl Oracle {
+    /// Reads `(peg, timestamp)` from the configured oracle, with the peg
+    /// rescaled to `PEG_DECIMALS`. Staleness is judged by `PegConfig`, not
+    /// by the oracle's own max age. A configured oracle whose calls fail
+    /// is `OracleUnavailable`, distinct from none being set.
+    pub fn read(env: &Env) -> Result<(i128, u64), PiCoinError> {
+        let oracle = Storage::get_oracle(env)
+            .ok_or(PiCoinError::OracleNotSet)?;
+        let client = PegOracleClient::new(env, &oracle);
+
+        let round = match client.try_latest_round_data() {
+            Ok(Ok(round)) => round,
+            _ => return Err(PiCoinError::OracleUnavailable),
+        };
+        let decimals = match client.try_decimals() {
+            Ok(Ok(decimals)) => decimals,
+            _ => return Err(PiCoinError::OracleUnavailable),
+        };
+
+        let peg = if decimals >= PEG_DECIMALS {
+            10i128
+                .checked_pow(decimals - PEG_DECIMALS)
+                .map(|factor| round.answer / factor)
+        } else {
+            10i128
+                .checked_pow(PEG_DECIMALS - decimals)
+                .and_then(|factor| round.answer.checked_mul(factor))
+        }
+        .ok_or(PiCoinError::InvalidOracleDecimals)?;
+
+        Ok((peg, round.updated_at))
+    }
+
+    /// Oracle peg, rejected once older than the configured max age.
+    pub fn fresh_peg(env: &Env) -> Result<i128, PiCoinError> {
+        let (peg, updated_at) = Self::read(env)?;
+
+        Self::check_age(env, &Storage::get_peg_config(env), updated_at)?;
+
+        Ok(peg)
+    }
+
+    pub fn status(env: &Env) -> Result<PegStatus, PiCoinError> {
+        let config = Storage::get_peg_config(env);
+        let (oracle_peg, updated_at) = Self::read(env)?;
+        let age_secs = Self::check_age(env, &config, updated_at)?;
+
+        let target_peg = Storage::get_data(env).peg_value;
+        let deviation_bps =
+            (oracle_peg - target_peg).abs() * BPS_DENOMINATOR / target_peg;
+
+        Ok(PegStatus {
+            on_peg: deviation_bps <= config.tolerance_bps as i128,
+            oracle_peg,
+            target_peg,
+            deviation_bps: deviation_bps as u32,
+            age_secs,
+        })
+    }
+
+    fn check_age(
+        env: &Env,
+        config: &PegConfig,
+        updated_at: u64,
+    ) -> Result<u64, PiCoinError> {
+        let age_secs = env.ledger().timestamp().saturating_sub(updated_at);
+
+        if age_secs > config.max_age_secs {
+            return Err(PiCoinError::OracleStale);
+        }
+
+        Ok(age_secs)
+    }
+}
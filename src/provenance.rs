@@ -0,0 +1,177 @@
+use soroban_sdk::{Env, Address, Map, Vec};
+
+use crate::events::Events;
+use crate::governance::Governance;
+use crate::storage::{AttributionMode, PiCoinSource, ProvenanceLot, Storage};
+
+/// Per-address provenance buckets. Each address holds at most one lot per
+/// `PiCoinSource`, ordered by when that source was first credited, and the
+/// lot amounts always sum to the address balance.
+pub struct Provenance;
+
+impl Provenance {
+    pub fn credit(
+        env: &Env,
+        addr: &Address,
+        source: &PiCoinSource,
+        amount: i128,
+    ) {
+        let mut lots = Storage::get_provenance_lots(env, addr);
+
+        Self::add(&mut lots, source, amount);
+        Self::store(env, addr, &lots);
+    }
+
+    pub fn credit_lots(
+        env: &Env,
+        addr: &Address,
+        incoming: &Vec<ProvenanceLot>,
+    ) {
+        let mut lots = Storage::get_provenance_lots(env, addr);
+
+        for lot in incoming.iter() {
+            Self::add(&mut lots, &lot.source, lot.amount);
+        }
+        Self::store(env, addr, &lots);
+    }
+
+    /// Removes `amount` from the address buckets using the configured
+    /// attribution mode and returns the lots that were taken.
+    pub fn debit(
+        env: &Env,
+        addr: &Address,
+        amount: i128,
+    ) -> Vec<ProvenanceLot> {
+        let lots = Storage::get_provenance_lots(env, addr);
+
+        let taken = match Storage::get_attribution_mode(env) {
+            AttributionMode::Fifo => {
+                Self::take_fifo(env, &lots, amount)
+            }
+            AttributionMode::ProRata => {
+                Self::take_pro_rata(env, &lots, amount)
+            }
+        };
+
+        let mut remaining = Vec::new(env);
+        for (lot, out) in lots.iter().zip(taken.iter()) {
+            if lot.amount > out.amount {
+                remaining.push_back(ProvenanceLot {
+                    source: lot.source,
+                    amount: lot.amount - out.amount,
+                });
+            }
+        }
+        Self::store(env, addr, &remaining);
+
+        let mut moved = Vec::new(env);
+        for out in taken.iter() {
+            if out.amount > 0 {
+                moved.push_back(out);
+            }
+        }
+
+        moved
+    }
+
+    pub fn breakdown(env: &Env, addr: &Address) -> Map<PiCoinSource, i128> {
+        let mut map = Map::new(env);
+
+        for lot in Storage::get_provenance_lots(env, addr).iter() {
+            map.set(lot.source, lot.amount);
+        }
+
+        map
+    }
+
+    // Every provenance write also moves the holder's voting checkpoint.
+    fn store(env: &Env, addr: &Address, lots: &Vec<ProvenanceLot>) {
+        Storage::set_provenance(env, addr, lots);
+        Governance::checkpoint(env, addr, lots);
+        Events::provenance(env, addr, lots);
+    }
+
+    fn add(
+        lots: &mut Vec<ProvenanceLot>,
+        source: &PiCoinSource,
+        amount: i128,
+    ) {
+        if amount == 0 {
+            return;
+        }
+
+        for i in 0..lots.len() {
+            let mut lot = lots.get_unchecked(i);
+            if lot.source == *source {
+                lot.amount += amount;
+                lots.set(i, lot);
+                return;
+            }
+        }
+
+        lots.push_back(ProvenanceLot {
+            source: source.clone(),
+            amount,
+        });
+    }
+
+    // Oldest bucket is drained first.
+    fn take_fifo(
+        env: &Env,
+        lots: &Vec<ProvenanceLot>,
+        amount: i128,
+    ) -> Vec<ProvenanceLot> {
+        let mut left = amount;
+        let mut taken = Vec::new(env);
+
+        for lot in lots.iter() {
+            let take = lot.amount.min(left);
+            left -= take;
+            taken.push_back(ProvenanceLot {
+                source: lot.source,
+                amount: take,
+            });
+        }
+
+        taken
+    }
+
+    // Every bucket contributes in proportion to its share of the balance;
+    // rounding dust is then drained oldest-first.
+    fn take_pro_rata(
+        env: &Env,
+        lots: &Vec<ProvenanceLot>,
+        amount: i128,
+    ) -> Vec<ProvenanceLot> {
+        let total: i128 = lots.iter().map(|lot| lot.amount).sum();
+        if total == 0 {
+            return Self::take_fifo(env, lots, amount);
+        }
+
+        let mut left = amount.min(total);
+        let mut taken = Vec::new(env);
+
+        for lot in lots.iter() {
+            let take = lot.amount * amount.min(total) / total;
+            left -= take;
+            taken.push_back(ProvenanceLot {
+                source: lot.source,
+                amount: take,
+            });
+        }
+
+        for i in 0..lots.len() {
+            if left == 0 {
+                break;
+            }
+
+            let mut out = taken.get_unchecked(i);
+            let extra = (lots.get_unchecked(i).amount - out.amount).min(left);
+            out.amount += extra;
+            left -= extra;
+            taken.set(i, out);
+        }
+
+        taken
+    }
+}
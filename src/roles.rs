@@ -0,0 +1,44 @@
+use soroban_sdk::{Env, Address};
+
+use crate::storage::{Role, Storage};
+use crate::error::PiCoinError;
+
+pub struct Roles;
+
+impl Roles {
+    /// Loads the current holder of `role` and requires its authorization.
+    pub fn require(env: &Env, role: Role) -> Result<Address, PiCoinError> {
+        let holder = Storage::get_role(env, role)
+            .ok_or(PiCoinError::Unauthorized)?;
+
+        holder.require_auth();
+
+        Ok(holder)
+    }
+
+    /// Step one of a role transfer: the current holder nominates a successor.
+    pub fn propose(
+        env: &Env,
+        role: Role,
+        new_holder: &Address,
+    ) -> Result<(), PiCoinError> {
+        Self::require(env, role)?;
+
+        Storage::set_pending_role(env, role, new_holder);
+
+        Ok(())
+    }
+
+    /// Step two of a role transfer: the nominee accepts and takes over.
+    pub fn accept(env: &Env, role: Role) -> Result<Address, PiCoinError> {
+        let pending = Storage::get_pending_role(env, role)
+            .ok_or(PiCoinError::Unauthorized)?;
+
+        pending.require_auth();
+
+        Storage::set_role(env, role, &pending);
+        Storage::remove_pending_role(env, role);
+
+        Ok(pending)
+    }
+}
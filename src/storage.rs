@@ -1,5 +1,14 @@
-use soroban_sdk::{contracttype, Env, Address, Symbol, Bytes};
+use soroban_sdk::{
+    contracttype, Env, Address, Bytes, BytesN, Map, TryFromVal, Val, Vec,
+};
 
+use crate::collateral::DEFAULT_COLLATERAL_RATIO_BPS;
+use crate::governance::{
+    DEFAULT_QUORUM, DEFAULT_SOURCE_WEIGHT_BPS, DEFAULT_THRESHOLD_BPS,
+    DEFAULT_VOTING_PERIOD_SECS,
+};
+use crate::oracle::{DEFAULT_ORACLE_MAX_AGE_SECS, DEFAULT_PEG_TOLERANCE_BPS};
+use crate::zkp::VerifyingKey;
 use crate::error::PiCoinError;
 
 #[contracttype]
@@ -11,60 +20,732 @@ pub enum PiCoinSource {
     Invalid,
 }
 
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct ProvenanceLot {
+    pub source: PiCoinSource,
+    pub amount: i128,
+}
+
+#[contracttype]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum AttributionMode {
+    Fifo,
+    ProRata,
+}
+
+#[contracttype]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Role {
+    Admin,
+    Governance,
+}
+
+#[contracttype]
+#[derive(Clone)]
+pub struct AllowanceValue {
+    pub amount: i128,
+    pub expiration_ledger: u32,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct CollateralPosition {
+    pub collateral: i128,
+    pub debt: i128,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct PegConfig {
+    pub tolerance_bps: u32,
+    pub max_age_secs: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct PegStatus {
+    pub on_peg: bool,
+    pub oracle_peg: i128,
+    pub target_peg: i128,
+    pub deviation_bps: u32,
+    pub age_secs: u64,
+}
+
+/// Ledger counts used for `extend_ttl`: once an entry's TTL drops below
+/// `*_threshold` it is extended back to `*_extend_to`.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct TtlConfig {
+    pub instance_threshold: u32,
+    pub instance_extend_to: u32,
+    pub account_threshold: u32,
+    pub account_extend_to: u32,
+}
+
+#[contracttype]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum VoteChoice {
+    For,
+    Against,
+    Abstain,
+}
+
+#[contracttype]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ProposalStatus {
+    Active,
+    Passed,
+    Rejected,
+    QuorumNotMet,
+}
+
+/// Voting rules for new proposals. `quorum` is the minimum voting power
+/// cast across all three choices; a proposal passes when `for` votes exceed
+/// `threshold_bps` of the `for + against` total.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct GovernanceConfig {
+    pub voting_period_secs: u64,
+    pub quorum: i128,
+    pub threshold_bps: u32,
+}
+
+/// Quorum, threshold and source weights are copied at creation so a config
+/// change cannot move the goalposts of a running vote. Voting power is read
+/// from balances as they stood before `snapshot_ledger`.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct Proposal {
+    pub id: u32,
+    pub proposer: Address,
+    pub description_hash: BytesN<32>,
+    pub start_time: u64,
+    pub end_time: u64,
+    pub snapshot_ledger: u32,
+    pub quorum: i128,
+    pub threshold_bps: u32,
+    pub source_weights: Map<PiCoinSource, u32>,
+    pub votes_for: i128,
+    pub votes_against: i128,
+    pub votes_abstain: i128,
+    pub status: ProposalStatus,
+}
+
+/// An account's provenance lots as of the end of `ledger`.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct VotingCheckpoint {
+    pub ledger: u32,
+    pub lots: Vec<ProvenanceLot>,
+}
+
 #[contracttype]
 #[derive(Clone)]
 pub struct PiCoinData {
     pub total_supply: i128,
+    pub circulating_supply: i128,
     pub peg_value: i128,
     pub anti_fraud_hash: Bytes,
 }
 
+#[contracttype]
+#[derive(Clone)]
+pub struct AllowanceDataKey {
+    pub from: Address,
+    pub spender: Address,
+}
+
+#[contracttype]
+#[derive(Clone)]
+pub struct CheckpointDataKey {
+    pub owner: Address,
+    pub index: u32,
+}
+
+#[contracttype]
+#[derive(Clone)]
+pub struct VoteDataKey {
+    pub proposal_id: u32,
+    pub voter: Address,
+}
+
+/// Every key the contract writes. Instance keys hold contract-wide config,
+/// persistent keys hold per-account state, temporary keys hold allowances.
+#[contracttype]
+#[derive(Clone)]
+pub enum DataKey {
+    // instance
+    Data,
+    Version,
+    Role(Role),
+    PendingRole(Role),
+    Collateral,
+    Oracle,
+    PegConfig,
+    CollateralRatio,
+    AttributionMode,
+    ZkpBase,
+    VerifyingKey,
+    TtlConfig,
+    GovernanceConfig,
+    ProposalCount,
+    SourceWeight(PiCoinSource),
+    // persistent
+    Balance(Address),
+    Provenance(Address),
+    Position(Address),
+    Proposal(u32),
+    Vote(VoteDataKey),
+    CheckpointCount(Address),
+    Checkpoint(CheckpointDataKey),
+    SpentNullifier(BytesN<32>),
+    // temporary
+    Allowance(AllowanceDataKey),
+}
+
+// ---------- TTL POLICY ----------
+// ~5s ledgers: 17_280 ledgers per day.
+const DAY_IN_LEDGERS: u32 = 17_280;
+
+pub const INSTANCE_BUMP_AMOUNT: u32 = 30 * DAY_IN_LEDGERS;
+pub const INSTANCE_LIFETIME_THRESHOLD: u32 =
+    INSTANCE_BUMP_AMOUNT - 7 * DAY_IN_LEDGERS;
+
+pub const ACCOUNT_BUMP_AMOUNT: u32 = 90 * DAY_IN_LEDGERS;
+pub const ACCOUNT_LIFETIME_THRESHOLD: u32 =
+    ACCOUNT_BUMP_AMOUNT - 30 * DAY_IN_LEDGERS;
+
 pub struct Storage;
 
 impl Storage {
+    // ---------- TTL ----------
+    pub fn set_ttl_config(env: &Env, config: &TtlConfig) {
+        env.storage().instance().set(&DataKey::TtlConfig, config);
+        Self::bump_instance(env);
+    }
+
+    pub fn get_ttl_config(env: &Env) -> TtlConfig {
+        env.storage()
+            .instance()
+            .get(&DataKey::TtlConfig)
+            .unwrap_or(TtlConfig {
+                instance_threshold: INSTANCE_LIFETIME_THRESHOLD,
+                instance_extend_to: INSTANCE_BUMP_AMOUNT,
+                account_threshold: ACCOUNT_LIFETIME_THRESHOLD,
+                account_extend_to: ACCOUNT_BUMP_AMOUNT,
+            })
+    }
+
+    pub fn bump_instance(env: &Env) {
+        let config = Self::get_ttl_config(env);
+
+        env.storage().instance().extend_ttl(
+            config.instance_threshold,
+            config.instance_extend_to,
+        );
+    }
+
+    fn bump_account(env: &Env, key: &DataKey) {
+        let config = Self::get_ttl_config(env);
+
+        env.storage().persistent().extend_ttl(
+            key,
+            config.account_threshold,
+            config.account_extend_to,
+        );
+    }
+
+    // Reads a per-account entry and keeps it alive while it is in use.
+    fn read_account<V>(env: &Env, key: &DataKey) -> Option<V>
+    where
+        V: TryFromVal<Env, Val>,
+    {
+        let value = env.storage().persistent().get(key);
+
+        if value.is_some() {
+            Self::bump_account(env, key);
+        }
+
+        value
+    }
+
+    /// Extends every per-account entry that exists for `addr`.
+    pub fn bump_account_entries(env: &Env, addr: &Address) {
+        let keys = [
+            DataKey::Balance(addr.clone()),
+            DataKey::Provenance(addr.clone()),
+            DataKey::Position(addr.clone()),
+        ];
+
+        for key in keys.iter() {
+            if env.storage().persistent().has(key) {
+                Self::bump_account(env, key);
+            }
+        }
+
+        // Only the latest checkpoint; a voting snapshot extends the older
+        // one it reads, so the cost does not grow with the history.
+        let count = Self::get_checkpoint_count(env, addr);
+        if count > 0 {
+            let key = DataKey::Checkpoint(CheckpointDataKey {
+                owner: addr.clone(),
+                index: count - 1,
+            });
+            if env.storage().persistent().has(&key) {
+                Self::bump_account(env, &key);
+            }
+        }
+    }
+
     // ---------- CORE DATA ----------
     pub fn set_data(env: &Env, data: &PiCoinData) {
         env.storage()
             .instance()
-            .set(&Symbol::new(env, "data"), data);
+            .set(&DataKey::Data, data);
+        Self::bump_instance(env);
     }
 
     pub fn get_data(env: &Env) -> PiCoinData {
         env.storage()
             .instance()
-            .get(&Symbol::new(env, "data"))
+            .get(&DataKey::Data)
             .unwrap()
     }
 
+    pub fn has_data(env: &Env) -> bool {
+        env.storage()
+            .instance()
+            .has(&DataKey::Data)
+    }
+
+    // ---------- VERSION ----------
+    pub fn set_version(env: &Env, version: u32) {
+        env.storage()
+            .instance()
+            .set(&DataKey::Version, &version);
+        Self::bump_instance(env);
+    }
+
+    pub fn get_version(env: &Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&DataKey::Version)
+            .unwrap_or(0)
+    }
+
+    // ---------- ROLES ----------
+    pub fn set_role(env: &Env, role: Role, addr: &Address) {
+        env.storage()
+            .instance()
+            .set(&DataKey::Role(role), addr);
+        Self::bump_instance(env);
+    }
+
+    pub fn get_role(env: &Env, role: Role) -> Option<Address> {
+        env.storage()
+            .instance()
+            .get(&DataKey::Role(role))
+    }
+
+    pub fn set_pending_role(env: &Env, role: Role, addr: &Address) {
+        env.storage()
+            .instance()
+            .set(&DataKey::PendingRole(role), addr);
+        Self::bump_instance(env);
+    }
+
+    pub fn get_pending_role(env: &Env, role: Role) -> Option<Address> {
+        env.storage()
+            .instance()
+            .get(&DataKey::PendingRole(role))
+    }
+
+    pub fn remove_pending_role(env: &Env, role: Role) {
+        env.storage()
+            .instance()
+            .remove(&DataKey::PendingRole(role));
+    }
+
+    // ---------- LINKED CONTRACTS ----------
+    pub fn set_collateral(env: &Env, addr: &Address) {
+        env.storage()
+            .instance()
+            .set(&DataKey::Collateral, addr);
+        Self::bump_instance(env);
+    }
+
+    pub fn get_collateral(env: &Env) -> Option<Address> {
+        env.storage()
+            .instance()
+            .get(&DataKey::Collateral)
+    }
+
+    pub fn set_oracle(env: &Env, addr: &Address) {
+        env.storage()
+            .instance()
+            .set(&DataKey::Oracle, addr);
+        Self::bump_instance(env);
+    }
+
+    pub fn get_oracle(env: &Env) -> Option<Address> {
+        env.storage()
+            .instance()
+            .get(&DataKey::Oracle)
+    }
+
+    pub fn set_peg_config(env: &Env, config: &PegConfig) {
+        env.storage()
+            .instance()
+            .set(&DataKey::PegConfig, config);
+        Self::bump_instance(env);
+    }
+
+    pub fn get_peg_config(env: &Env) -> PegConfig {
+        env.storage()
+            .instance()
+            .get(&DataKey::PegConfig)
+            .unwrap_or(PegConfig {
+                tolerance_bps: DEFAULT_PEG_TOLERANCE_BPS,
+                max_age_secs: DEFAULT_ORACLE_MAX_AGE_SECS,
+            })
+    }
+
+    // ---------- COLLATERAL ----------
+    pub fn set_collateral_ratio(env: &Env, ratio_bps: u32) {
+        env.storage()
+            .instance()
+            .set(&DataKey::CollateralRatio, &ratio_bps);
+        Self::bump_instance(env);
+    }
+
+    pub fn get_collateral_ratio(env: &Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&DataKey::CollateralRatio)
+            .unwrap_or(DEFAULT_COLLATERAL_RATIO_BPS)
+    }
+
+    pub fn set_position(
+        env: &Env,
+        owner: &Address,
+        position: &CollateralPosition,
+    ) {
+        let key = DataKey::Position(owner.clone());
+
+        env.storage().persistent().set(&key, position);
+        Self::bump_account(env, &key);
+    }
+
+    pub fn get_position(env: &Env, owner: &Address) -> CollateralPosition {
+        Self::read_account(env, &DataKey::Position(owner.clone()))
+            .unwrap_or(CollateralPosition {
+                collateral: 0,
+                debt: 0,
+            })
+    }
+
     // ---------- PROVENANCE ----------
     pub fn set_provenance(
         env: &Env,
         addr: &Address,
-        source: &PiCoinSource,
+        lots: &Vec<ProvenanceLot>,
     ) {
-        env.storage().persistent().set(addr, source);
+        let key = DataKey::Provenance(addr.clone());
+
+        env.storage().persistent().set(&key, lots);
+        Self::bump_account(env, &key);
     }
 
+    pub fn get_provenance_lots(
+        env: &Env,
+        addr: &Address,
+    ) -> Vec<ProvenanceLot> {
+        Self::read_account(env, &DataKey::Provenance(addr.clone()))
+            .unwrap_or(Vec::new(env))
+    }
+
+    /// Oldest source still backing part of the balance.
     pub fn get_provenance(
         env: &Env,
         addr: &Address,
     ) -> Result<PiCoinSource, PiCoinError> {
+        Self::get_provenance_lots(env, addr)
+            .first()
+            .map(|lot| lot.source)
+            .ok_or(PiCoinError::InvalidSource)
+    }
+
+    pub fn set_attribution_mode(env: &Env, mode: AttributionMode) {
+        env.storage()
+            .instance()
+            .set(&DataKey::AttributionMode, &mode);
+        Self::bump_instance(env);
+    }
+
+    pub fn get_attribution_mode(env: &Env) -> AttributionMode {
+        env.storage()
+            .instance()
+            .get(&DataKey::AttributionMode)
+            .unwrap_or(AttributionMode::Fifo)
+    }
+
+    // ---------- BALANCES ----------
+    pub fn get_balance(env: &Env, addr: &Address) -> i128 {
+        Self::read_account(env, &DataKey::Balance(addr.clone()))
+            .unwrap_or(0)
+    }
+
+    pub fn set_balance(env: &Env, addr: &Address, amount: i128) {
+        let key = DataKey::Balance(addr.clone());
+
+        env.storage().persistent().set(&key, &amount);
+        Self::bump_account(env, &key);
+    }
+
+    // ---------- ALLOWANCES ----------
+    pub fn get_allowance(
+        env: &Env,
+        from: &Address,
+        spender: &Address,
+    ) -> AllowanceValue {
+        let key = DataKey::Allowance(AllowanceDataKey {
+            from: from.clone(),
+            spender: spender.clone(),
+        });
+
+        match env.storage().temporary().get::<_, AllowanceValue>(&key) {
+            Some(allowance)
+                if allowance.expiration_ledger >= env.ledger().sequence() =>
+            {
+                allowance
+            }
+            _ => AllowanceValue {
+                amount: 0,
+                expiration_ledger: 0,
+            },
+        }
+    }
+
+    pub fn set_allowance(
+        env: &Env,
+        from: &Address,
+        spender: &Address,
+        amount: i128,
+        expiration_ledger: u32,
+    ) -> Result<(), PiCoinError> {
+        let ledger = env.ledger().sequence();
+
+        if amount > 0 && expiration_ledger < ledger {
+            return Err(PiCoinError::InvalidExpiration);
+        }
+
+        let key = DataKey::Allowance(AllowanceDataKey {
+            from: from.clone(),
+            spender: spender.clone(),
+        });
+        let allowance = AllowanceValue {
+            amount,
+            expiration_ledger,
+        };
+
+        env.storage().temporary().set(&key, &allowance);
+
+        if amount > 0 {
+            env.storage().temporary().extend_ttl(
+                &key,
+                expiration_ledger - ledger,
+                expiration_ledger - ledger,
+            );
+        }
+
+        Ok(())
+    }
+
+    // ---------- GOVERNANCE ----------
+    pub fn set_governance_config(env: &Env, config: &GovernanceConfig) {
+        env.storage()
+            .instance()
+            .set(&DataKey::GovernanceConfig, config);
+        Self::bump_instance(env);
+    }
+
+    pub fn get_governance_config(env: &Env) -> GovernanceConfig {
+        env.storage()
+            .instance()
+            .get(&DataKey::GovernanceConfig)
+            .unwrap_or(GovernanceConfig {
+                voting_period_secs: DEFAULT_VOTING_PERIOD_SECS,
+                quorum: DEFAULT_QUORUM,
+                threshold_bps: DEFAULT_THRESHOLD_BPS,
+            })
+    }
+
+    pub fn set_source_weight(
+        env: &Env,
+        source: &PiCoinSource,
+        weight_bps: u32,
+    ) {
+        env.storage()
+            .instance()
+            .set(&DataKey::SourceWeight(source.clone()), &weight_bps);
+        Self::bump_instance(env);
+    }
+
+    pub fn get_source_weight(env: &Env, source: &PiCoinSource) -> u32 {
+        env.storage()
+            .instance()
+            .get(&DataKey::SourceWeight(source.clone()))
+            .unwrap_or(DEFAULT_SOURCE_WEIGHT_BPS)
+    }
+
+    /// Hands out proposal ids starting at 1.
+    pub fn next_proposal_id(env: &Env) -> u32 {
+        let id = env
+            .storage()
+            .instance()
+            .get::<_, u32>(&DataKey::ProposalCount)
+            .unwrap_or(0)
+            + 1;
+
+        env.storage().instance().set(&DataKey::ProposalCount, &id);
+        Self::bump_instance(env);
+
+        id
+    }
+
+    pub fn set_proposal(env: &Env, proposal: &Proposal) {
+        let key = DataKey::Proposal(proposal.id);
+
+        env.storage().persistent().set(&key, proposal);
+        Self::bump_account(env, &key);
+    }
+
+    pub fn get_proposal(env: &Env, id: u32) -> Option<Proposal> {
+        Self::read_account(env, &DataKey::Proposal(id))
+    }
+
+    pub fn set_vote(
+        env: &Env,
+        proposal_id: u32,
+        voter: &Address,
+        choice: VoteChoice,
+    ) {
+        let key = DataKey::Vote(VoteDataKey {
+            proposal_id,
+            voter: voter.clone(),
+        });
+
+        env.storage().persistent().set(&key, &choice);
+        Self::bump_account(env, &key);
+    }
+
+    pub fn get_vote(
+        env: &Env,
+        proposal_id: u32,
+        voter: &Address,
+    ) -> Option<VoteChoice> {
+        Self::read_account(
+            env,
+            &DataKey::Vote(VoteDataKey {
+                proposal_id,
+                voter: voter.clone(),
+            }),
+        )
+    }
+
+    pub fn get_checkpoint_count(env: &Env, owner: &Address) -> u32 {
+        Self::read_account(env, &DataKey::CheckpointCount(owner.clone()))
+            .unwrap_or(0)
+    }
+
+    pub fn set_checkpoint_count(env: &Env, owner: &Address, count: u32) {
+        let key = DataKey::CheckpointCount(owner.clone());
+
+        env.storage().persistent().set(&key, &count);
+        Self::bump_account(env, &key);
+    }
+
+    pub fn get_checkpoint(
+        env: &Env,
+        owner: &Address,
+        index: u32,
+    ) -> Option<VotingCheckpoint> {
+        Self::read_account(
+            env,
+            &DataKey::Checkpoint(CheckpointDataKey {
+                owner: owner.clone(),
+                index,
+            }),
+        )
+    }
+
+    /// Reads a checkpoint without extending it, for search probes.
+    pub fn peek_checkpoint(
+        env: &Env,
+        owner: &Address,
+        index: u32,
+    ) -> Option<VotingCheckpoint> {
         env.storage()
             .persistent()
-            .get(addr)
-            .ok_or(PiCoinError::InvalidSource)
+            .get(&DataKey::Checkpoint(CheckpointDataKey {
+                owner: owner.clone(),
+                index,
+            }))
+    }
+
+    pub fn set_checkpoint(
+        env: &Env,
+        owner: &Address,
+        index: u32,
+        checkpoint: &VotingCheckpoint,
+    ) {
+        let key = DataKey::Checkpoint(CheckpointDataKey {
+            owner: owner.clone(),
+            index,
+        });
+
+        env.storage().persistent().set(&key, checkpoint);
+        Self::bump_account(env, &key);
     }
 
     // ---------- ZKP GATE ----------
     pub fn set_zkp_base(env: &Env, value: &Bytes) {
         env.storage()
             .instance()
-            .set(&Symbol::new(env, "zkp_base"), value);
+            .set(&DataKey::ZkpBase, value);
+        Self::bump_instance(env);
     }
 
     pub fn has_zkp_base(env: &Env) -> bool {
         env.storage()
             .instance()
-            .has(&Symbol::new(env, "zkp_base"))
+            .has(&DataKey::ZkpBase)
+    }
+
+    pub fn set_verifying_key(env: &Env, vk: &VerifyingKey) {
+        env.storage()
+            .instance()
+            .set(&DataKey::VerifyingKey, vk);
+        Self::bump_instance(env);
+    }
+
+    pub fn get_verifying_key(env: &Env) -> Option<VerifyingKey> {
+        env.storage()
+            .instance()
+            .get(&DataKey::VerifyingKey)
+    }
+
+    pub fn is_nullifier_spent(env: &Env, nullifier: &BytesN<32>) -> bool {
+        env.storage()
+            .persistent()
+            .has(&DataKey::SpentNullifier(nullifier.clone()))
+    }
+
+    pub fn set_nullifier_spent(env: &Env, nullifier: &BytesN<32>) {
+        let key = DataKey::SpentNullifier(nullifier.clone());
+
+        env.storage().persistent().set(&key, &true);
+        Self::bump_account(env, &key);
     }
 }
@@ -0,0 +1,1026 @@
+#![cfg(test)]
+
+use super::*;
+use crate::storage::{CheckpointDataKey, DataKey, ProvenanceLot};
+use soroban_sdk::{
+    contract, contractimpl, symbol_short,
+    testutils::{
+        storage::Persistent as _, Address as _, Events as _, Ledger,
+        MockAuth, MockAuthInvoke,
+    },
+    crypto::bls12_381::{Fr, G1Affine, G2Affine},
+    token::{StellarAssetClient, TokenClient},
+    vec, xdr, Address, Bytes, Env, IntoVal, String, TryFromVal, Val, U256,
+};
+
+#[contract]
+pub struct MockOracle;
+
+#[contractimpl]
+impl MockOracle {
+    pub fn set_peg(env: Env, peg: i128) {
+        env.storage().instance().set(&symbol_short!("peg"), &peg);
+        env.storage()
+            .instance()
+            .set(&symbol_short!("ts"), &env.ledger().timestamp());
+    }
+
+    pub fn set_decimals(env: Env, decimals: u32) {
+        env.storage().instance().set(&symbol_short!("dec"), &decimals);
+    }
+
+    pub fn decimals(env: Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&symbol_short!("dec"))
+            .unwrap_or(collateral::PEG_DECIMALS)
+    }
+
+    pub fn latest_round_data(env: Env) -> oracle::RoundData {
+        let answer = env.storage().instance().get(&symbol_short!("peg"));
+        let updated_at = env.storage().instance().get(&symbol_short!("ts"));
+
+        oracle::RoundData {
+            round_id: 1,
+            answer: answer.unwrap(),
+            started_at: updated_at.unwrap(),
+            updated_at: updated_at.unwrap(),
+            answered_in_round: 1,
+        }
+    }
+}
+
+struct Setup<'a> {
+    contract_id: Address,
+    client: PiCoinContractClient<'a>,
+    collateral: StellarAssetClient<'a>,
+    oracle: MockOracleClient<'a>,
+}
+
+fn setup(env: &Env) -> Setup<'_> {
+    env.mock_all_auths();
+
+    let collateral_id = env
+        .register_stellar_asset_contract_v2(Address::generate(env))
+        .address();
+    let oracle_id = env.register(MockOracle, ());
+    let oracle = MockOracleClient::new(env, &oracle_id);
+    oracle.set_peg(&collateral::PEG_SCALE);
+
+    let contract_id = env.register(PiCoinContract, ());
+    let client = PiCoinContractClient::new(env, &contract_id);
+
+    client.initialize(
+        &Address::generate(env),
+        &collateral_id,
+        &oracle_id,
+        &Address::generate(env),
+    );
+
+    client.set_zkp_base(&Bytes::from_slice(env, b"zkp-seed"));
+
+    Setup {
+        contract_id,
+        client,
+        collateral: StellarAssetClient::new(env, &collateral_id),
+        oracle,
+    }
+}
+
+fn funded_user(env: &Env, s: &Setup) -> Address {
+    let user = Address::generate(env);
+    s.collateral.mint(&user, &1_000_000_000_000);
+    user
+}
+
+#[test]
+fn test_token_metadata() {
+    let env = Env::default();
+    let s = setup(&env);
+    let client = &s.client;
+
+    assert_eq!(client.decimals(), 7);
+    assert_eq!(client.name(), String::from_str(&env, "Pi Coin"));
+    assert_eq!(client.symbol(), String::from_str(&env, "PI"));
+}
+
+#[test]
+fn test_mint_transfer_burn() {
+    let env = Env::default();
+    let s = setup(&env);
+    let client = &s.client;
+
+    let user = funded_user(&env, &s);
+    let receiver = Address::generate(&env);
+
+    client.mint(&user, &1_000_000, &PiCoinSource::Mining);
+    assert_eq!(client.balance(&user), 1_000_000);
+
+    client.transfer(&user, &receiver, &400_000);
+    assert_eq!(client.balance(&user), 600_000);
+    assert_eq!(client.balance(&receiver), 400_000);
+
+    client.burn(&user, &100_000);
+    assert_eq!(client.balance(&user), 500_000);
+
+    assert!(client.try_transfer(&user, &receiver, &500_001).is_err());
+    assert!(client.try_transfer(&user, &receiver, &-1).is_err());
+}
+
+#[test]
+fn test_allowance_flow() {
+    let env = Env::default();
+    let s = setup(&env);
+    let client = &s.client;
+
+    let owner = funded_user(&env, &s);
+    let spender = Address::generate(&env);
+    let receiver = Address::generate(&env);
+
+    client.mint(&owner, &1_000_000, &PiCoinSource::Rewards);
+    client.approve(&owner, &spender, &300_000, &1_000);
+    assert_eq!(client.allowance(&owner, &spender), 300_000);
+
+    client.transfer_from(&spender, &owner, &receiver, &200_000);
+    assert_eq!(client.allowance(&owner, &spender), 100_000);
+    assert_eq!(client.balance(&receiver), 200_000);
+
+    client.burn_from(&spender, &owner, &100_000);
+    assert_eq!(client.allowance(&owner, &spender), 0);
+    assert_eq!(client.balance(&owner), 700_000);
+
+    assert!(client
+        .try_transfer_from(&spender, &owner, &receiver, &1)
+        .is_err());
+}
+
+#[test]
+fn test_supply_cap() {
+    let env = Env::default();
+    let s = setup(&env);
+    let client = &s.client;
+    let contract_id = &s.contract_id;
+
+    let user = funded_user(&env, &s);
+
+    env.as_contract(contract_id, || {
+        let mut data = Storage::get_data(&env);
+        data.total_supply = 1_500_000;
+        Storage::set_data(&env, &data);
+    });
+
+    client.mint(&user, &1_000_000, &PiCoinSource::Mining);
+    assert_eq!(client.circulating_supply(), 1_000_000);
+    assert_eq!(client.max_supply(), 1_500_000);
+
+    assert_eq!(
+        client.try_mint(&user, &500_001, &PiCoinSource::Mining),
+        Err(Ok(PiCoinError::SupplyCapExceeded))
+    );
+
+    client.burn(&user, &250_000);
+    assert_eq!(client.circulating_supply(), 750_000);
+
+    client.mint(&user, &750_000, &PiCoinSource::Mining);
+    assert_eq!(client.circulating_supply(), client.max_supply());
+}
+
+#[test]
+fn test_mint_requires_admin() {
+    let env = Env::default();
+    let s = setup(&env);
+    let client = &s.client;
+    let contract_id = &s.contract_id;
+
+    let admin = client.get_role(&Role::Admin).unwrap();
+    let intruder = Address::generate(&env);
+    let user = funded_user(&env, &s);
+
+    env.mock_auths(&[MockAuth {
+        address: &intruder,
+        invoke: &MockAuthInvoke {
+            contract: contract_id,
+            fn_name: "mint",
+            args: (&user, 1_000_i128, PiCoinSource::Mining).into_val(&env),
+            sub_invokes: &[],
+        },
+    }]);
+    assert!(client
+        .try_mint(&user, &1_000, &PiCoinSource::Mining)
+        .is_err());
+
+    env.mock_all_auths();
+    client.mint(&user, &1_000, &PiCoinSource::Mining);
+    assert!(env.auths().iter().any(|(addr, _)| *addr == admin));
+    assert_eq!(client.balance(&user), 1_000);
+}
+
+#[test]
+fn test_two_step_role_transfer() {
+    let env = Env::default();
+    let s = setup(&env);
+    let client = &s.client;
+
+    let old_admin = client.get_role(&Role::Admin).unwrap();
+    let new_admin = Address::generate(&env);
+
+    assert!(client.try_accept_role(&Role::Admin).is_err());
+
+    client.transfer_role(&Role::Admin, &new_admin);
+    assert_eq!(client.get_role(&Role::Admin), Some(old_admin));
+
+    client.accept_role(&Role::Admin);
+    assert_eq!(client.get_role(&Role::Admin), Some(new_admin));
+    assert!(client.try_accept_role(&Role::Admin).is_err());
+}
+
+#[test]
+fn test_initialize_only_once() {
+    let env = Env::default();
+    let s = setup(&env);
+    let client = &s.client;
+
+    assert_eq!(client.version(), CONTRACT_VERSION);
+
+    let result = client.try_initialize(
+        &Address::generate(&env),
+        &Address::generate(&env),
+        &Address::generate(&env),
+        &Address::generate(&env),
+    );
+    assert!(result.is_err());
+
+    assert_eq!(client.migrate(), CONTRACT_VERSION);
+}
+
+#[test]
+fn test_collateral_backed_mint_and_redeem() {
+    let env = Env::default();
+    let s = setup(&env);
+    let client = &s.client;
+    let collateral = TokenClient::new(&env, &s.collateral.address);
+
+    let user = funded_user(&env, &s);
+    let start = collateral.balance(&user);
+
+    // 150% ratio at a peg of 1.0
+    client.mint(&user, &1_000_000, &PiCoinSource::Mining);
+    assert_eq!(
+        client.position(&user),
+        CollateralPosition {
+            collateral: 1_500_000,
+            debt: 1_000_000,
+        }
+    );
+    assert_eq!(collateral.balance(&s.contract_id), 1_500_000);
+
+    assert!(client.try_withdraw_collateral(&user, &1).is_err());
+
+    client.deposit_collateral(&user, &500_000);
+    client.withdraw_collateral(&user, &500_000);
+
+    // Peg doubles: the position is now under-collateralized for withdrawals
+    s.oracle.set_peg(&(2 * collateral::PEG_SCALE));
+    assert!(client.try_withdraw_collateral(&user, &1).is_err());
+
+    assert_eq!(client.redeem(&user, &500_000), 750_000);
+    assert_eq!(client.balance(&user), 500_000);
+    assert_eq!(collateral.balance(&user), start - 750_000);
+
+    assert!(client.try_set_collateral_ratio(&9_999).is_err());
+    client.set_collateral_ratio(&20_000);
+    assert_eq!(client.collateral_ratio(), 20_000);
+}
+
+#[test]
+fn test_verify_peg_against_oracle() {
+    let env = Env::default();
+    let s = setup(&env);
+    let client = &s.client;
+
+    let holder = funded_user(&env, &s);
+    client.mint(&holder, &1_000, &PiCoinSource::Mining);
+
+    // Target peg is 314_159.000000; oracle reports 2% above it, outside
+    // the default 1% tolerance
+    s.oracle.set_peg(&320_442_180_000);
+
+    let status = client.verify_peg(&holder);
+    assert!(!status.on_peg);
+    assert_eq!(status.deviation_bps, 200);
+    assert_eq!(status.target_peg, 314_159_000_000);
+
+    client.set_peg_config(&PegConfig {
+        tolerance_bps: 200,
+        max_age_secs: 600,
+    });
+    assert!(client.verify_peg(&holder).on_peg);
+
+    env.ledger().with_mut(|li| li.timestamp += 601);
+    assert!(client.try_verify_peg(&holder).is_err());
+}
+
+#[test]
+fn test_oracle_peg_is_rescaled_by_decimals() {
+    let env = Env::default();
+    let s = setup(&env);
+    let client = &s.client;
+
+    let holder = funded_user(&env, &s);
+    client.mint(&holder, &1_000, &PiCoinSource::Mining);
+
+    // Same 2% premium as above, quoted with three decimals
+    s.oracle.set_decimals(&3);
+    s.oracle.set_peg(&320_442_180);
+
+    let status = client.verify_peg(&holder);
+    assert_eq!(status.oracle_peg, 320_442_180_000);
+    assert_eq!(status.deviation_bps, 200);
+
+    s.oracle.set_decimals(&60);
+    assert_eq!(
+        client.try_verify_peg(&holder),
+        Err(Ok(PiCoinError::InvalidOracleDecimals))
+    );
+}
+
+#[test]
+fn test_failing_oracle_is_unavailable() {
+    let env = Env::default();
+    let s = setup(&env);
+    let client = &s.client;
+
+    let holder = funded_user(&env, &s);
+    client.mint(&holder, &1_000, &PiCoinSource::Mining);
+
+    // A fresh mock has no round yet, so latest_round_data traps
+    client.set_oracle(&env.register(MockOracle, ()));
+    assert_eq!(
+        client.try_verify_peg(&holder),
+        Err(Ok(PiCoinError::OracleUnavailable))
+    );
+
+    // So does a contract that is not an oracle at all
+    client.set_oracle(&s.collateral.address);
+    assert_eq!(
+        client.try_verify_peg(&holder),
+        Err(Ok(PiCoinError::OracleUnavailable))
+    );
+}
+
+// ---------- GROTH16 TEST VECTORS ----------
+//
+// Synthetic vectors built from scalar multiples of hash-to-curve base points
+// g (G1) and h (G2), so the pairing equation can be balanced by hand:
+//
+//   alpha = 2g, beta = 3h, gamma = 5h, delta = h, ic = [7g, 11g, 13g]
+//   public inputs (n, x), nullifier and transfer binding
+//     =>  vk_x = (7 + 11n + 13x)g
+//   A = 40g, B = 40h, C = (40 * 40 - 2 * 3 - 5 * (7 + 11n + 13x))g
+//
+// e(-A, B) * e(alpha, beta) * e(vk_x, gamma) * e(C, delta) = 1
+
+fn fr(env: &Env, n: u32) -> Fr {
+    Fr::from_u256(U256::from_u32(env, n))
+}
+
+fn base_points(env: &Env) -> (G1Affine, G2Affine) {
+    let bls = env.crypto().bls12_381();
+    let dst = Bytes::from_slice(env, b"PICOIN-ZKP-TEST-V1");
+
+    (
+        bls.hash_to_g1(&Bytes::from_slice(env, b"g"), &dst),
+        bls.hash_to_g2(&Bytes::from_slice(env, b"h"), &dst),
+    )
+}
+
+fn test_vectors(
+    env: &Env,
+    nullifier: u32,
+    x: &Fr,
+) -> (VerifyingKey, Groth16Proof, Vec<Fr>) {
+    let bls = env.crypto().bls12_381();
+    let (g, h) = base_points(env);
+
+    let vk = VerifyingKey {
+        alpha: bls.g1_mul(&g, &fr(env, 2)),
+        beta: bls.g2_mul(&h, &fr(env, 3)),
+        gamma: bls.g2_mul(&h, &fr(env, 5)),
+        delta: h.clone(),
+        ic: vec![
+            env,
+            bls.g1_mul(&g, &fr(env, 7)),
+            bls.g1_mul(&g, &fr(env, 11)),
+            bls.g1_mul(&g, &fr(env, 13)),
+        ],
+    };
+
+    let n = fr(env, nullifier);
+    let vk_x =
+        fr(env, 7) + fr(env, 11) * n.clone() + fr(env, 13) * x.clone();
+    let c = fr(env, 1_594) - fr(env, 5) * vk_x;
+
+    let proof = Groth16Proof {
+        a: bls.g1_mul(&g, &fr(env, 40)),
+        b: bls.g2_mul(&h, &fr(env, 40)),
+        c: bls.g1_mul(&g, &c),
+    };
+
+    (vk, proof, vec![env, n, x.clone()])
+}
+
+fn proof_setup(env: &Env) -> (Setup<'_>, Address, Address) {
+    let s = setup(env);
+    let from = funded_user(env, &s);
+    let to = Address::generate(env);
+
+    s.client.mint(&from, &1_000, &PiCoinSource::Mining);
+
+    (s, from, to)
+}
+
+#[test]
+fn test_transfer_with_valid_proof() {
+    let env = Env::default();
+    let (s, from, to) = proof_setup(&env);
+    let binding = s.client.transfer_binding(&from, &to, &100);
+    let (vk, proof, inputs) = test_vectors(&env, 1, &binding);
+
+    assert_eq!(
+        s.client
+            .try_transfer_with_proof(&from, &to, &100, &proof, &inputs),
+        Err(Ok(PiCoinError::VerifyingKeyNotSet))
+    );
+
+    s.client.set_verifying_key(&vk);
+    s.client.transfer_with_proof(&from, &to, &100, &proof, &inputs);
+
+    assert_eq!(s.client.balance(&from), 900);
+    assert_eq!(s.client.balance(&to), 100);
+
+    // Neither the same proof nor a re-randomised copy of it, which still
+    // verifies, can move funds a second time
+    let mauled = Groth16Proof {
+        a: -proof.a.clone(),
+        b: -proof.b.clone(),
+        c: proof.c.clone(),
+    };
+    assert_eq!(
+        env.as_contract(&s.client.address, || {
+            Zkp::verify(&env, &mauled, &inputs)
+        }),
+        Ok(())
+    );
+    for replay in [&proof, &mauled] {
+        assert_eq!(
+            s.client
+                .try_transfer_with_proof(&from, &to, &100, replay, &inputs),
+            Err(Ok(PiCoinError::ProofReplayed))
+        );
+    }
+    assert_eq!(s.client.balance(&from), 900);
+
+    // A proof with a fresh nullifier is a new transfer
+    let (_, proof, inputs) = test_vectors(&env, 2, &binding);
+    s.client.transfer_with_proof(&from, &to, &100, &proof, &inputs);
+    assert_eq!(s.client.balance(&to), 200);
+}
+
+#[test]
+fn test_transfer_with_wrong_public_input() {
+    let env = Env::default();
+    let (s, from, to) = proof_setup(&env);
+    let binding = s.client.transfer_binding(&from, &to, &100);
+    let (vk, proof, inputs) = test_vectors(&env, 1, &binding);
+
+    s.client.set_verifying_key(&vk);
+
+    // A valid proof for (from, to, 100) cannot be reused for other terms
+    let other = Address::generate(&env);
+    for (recipient, amount) in [(&other, 100), (&to, 500)] {
+        assert_eq!(
+            s.client.try_transfer_with_proof(
+                &from, recipient, &amount, &proof, &inputs,
+            ),
+            Err(Ok(PiCoinError::ProofNotBound))
+        );
+    }
+    assert_eq!(s.client.balance(&from), 1_000);
+}
+
+#[test]
+fn test_transfer_with_tampered_proof() {
+    let env = Env::default();
+    let (s, from, to) = proof_setup(&env);
+    let binding = s.client.transfer_binding(&from, &to, &100);
+    let (vk, mut proof, inputs) = test_vectors(&env, 1, &binding);
+    let (g, _) = base_points(&env);
+
+    s.client.set_verifying_key(&vk);
+
+    proof.c = env.crypto().bls12_381().g1_mul(&g, &fr(&env, 845));
+    assert_eq!(
+        s.client
+            .try_transfer_with_proof(&from, &to, &100, &proof, &inputs),
+        Err(Ok(PiCoinError::InvalidProof))
+    );
+}
+
+#[test]
+fn test_transfer_with_wrong_input_count() {
+    let env = Env::default();
+    let (s, from, to) = proof_setup(&env);
+    let binding = s.client.transfer_binding(&from, &to, &100);
+    let (vk, proof, _) = test_vectors(&env, 1, &binding);
+
+    s.client.set_verifying_key(&vk);
+
+    for inputs in [
+        vec![&env, binding.clone()],
+        vec![&env, fr(&env, 0), fr(&env, 1), binding],
+    ] {
+        assert_eq!(
+            s.client
+                .try_transfer_with_proof(&from, &to, &100, &proof, &inputs),
+            Err(Ok(PiCoinError::MalformedProof))
+        );
+    }
+}
+
+#[test]
+fn test_provenance_fifo_attribution() {
+    let env = Env::default();
+    let s = setup(&env);
+    let client = &s.client;
+
+    let user = funded_user(&env, &s);
+    let receiver = Address::generate(&env);
+
+    client.mint(&user, &600, &PiCoinSource::Mining);
+    client.mint(&user, &400, &PiCoinSource::P2P);
+
+    let breakdown = client.provenance_breakdown(&user);
+    assert_eq!(breakdown.get(PiCoinSource::Mining), Some(600));
+    assert_eq!(breakdown.get(PiCoinSource::P2P), Some(400));
+
+    client.transfer(&user, &receiver, &700);
+
+    let sent = client.provenance_breakdown(&receiver);
+    assert_eq!(sent.get(PiCoinSource::Mining), Some(600));
+    assert_eq!(sent.get(PiCoinSource::P2P), Some(100));
+
+    let kept = client.provenance_breakdown(&user);
+    assert_eq!(kept.get(PiCoinSource::Mining), None);
+    assert_eq!(kept.get(PiCoinSource::P2P), Some(300));
+}
+
+#[test]
+fn test_provenance_pro_rata_attribution() {
+    let env = Env::default();
+    let s = setup(&env);
+    let client = &s.client;
+
+    let user = funded_user(&env, &s);
+    let receiver = Address::generate(&env);
+
+    client.set_attribution_mode(&AttributionMode::ProRata);
+    client.mint(&user, &600, &PiCoinSource::Mining);
+    client.mint(&user, &400, &PiCoinSource::Rewards);
+
+    client.transfer(&user, &receiver, &501);
+
+    let sent = client.provenance_breakdown(&receiver);
+    assert_eq!(sent.get(PiCoinSource::Mining), Some(301));
+    assert_eq!(sent.get(PiCoinSource::Rewards), Some(200));
+
+    client.burn(&user, &499);
+    assert!(client.provenance_breakdown(&user).is_empty());
+    assert!(!client.verify_ecosystem_entry(&user));
+}
+
+// ---------- STATE ARCHIVAL ----------
+
+fn account_ttl(env: &Env, s: &Setup, key: &DataKey) -> u32 {
+    env.as_contract(&s.contract_id, || env.storage().persistent().get_ttl(key))
+}
+
+#[test]
+fn test_account_entries_bumped_on_touch() {
+    let env = Env::default();
+    let s = setup(&env);
+    let client = &s.client;
+
+    client.set_ttl_config(&TtlConfig {
+        instance_threshold: 50_000,
+        instance_extend_to: 100_000,
+        account_threshold: 9_000,
+        account_extend_to: 10_000,
+    });
+
+    let user = funded_user(&env, &s);
+    client.mint(&user, &1_000, &PiCoinSource::Mining);
+
+    let balance_key = DataKey::Balance(user.clone());
+    assert_eq!(account_ttl(&env, &s, &balance_key), 10_000);
+
+    env.ledger().with_mut(|li| li.sequence_number += 2_000);
+    assert_eq!(account_ttl(&env, &s, &balance_key), 8_000);
+
+    // Reading the balance falls under the threshold and extends it again
+    assert_eq!(client.balance(&user), 1_000);
+    assert_eq!(account_ttl(&env, &s, &balance_key), 10_000);
+}
+
+#[test]
+fn test_bump_accounts_keeps_dormant_holders_alive() {
+    let env = Env::default();
+    let s = setup(&env);
+    let client = &s.client;
+
+    client.set_ttl_config(&TtlConfig {
+        instance_threshold: 50_000,
+        instance_extend_to: 100_000,
+        account_threshold: 9_000,
+        account_extend_to: 10_000,
+    });
+
+    let user = funded_user(&env, &s);
+    client.mint(&user, &1_000, &PiCoinSource::Rewards);
+
+    // Twice past the original TTL, bumping in between
+    for _ in 0..2 {
+        env.ledger().with_mut(|li| li.sequence_number += 8_000);
+        client.bump_accounts(&vec![&env, user.clone()]);
+
+        for key in [
+            DataKey::Balance(user.clone()),
+            DataKey::Provenance(user.clone()),
+            DataKey::Position(user.clone()),
+            DataKey::CheckpointCount(user.clone()),
+            DataKey::Checkpoint(CheckpointDataKey {
+                owner: user.clone(),
+                index: 0,
+            }),
+        ] {
+            assert_eq!(account_ttl(&env, &s, &key), 10_000);
+        }
+    }
+
+    env.ledger().with_mut(|li| li.sequence_number += 9_999);
+    assert_eq!(client.balance(&user), 1_000);
+    assert_eq!(
+        client.provenance_breakdown(&user).get(PiCoinSource::Rewards),
+        Some(1_000)
+    );
+}
+
+#[test]
+fn test_checkpoints_bumped_when_latest_or_read() {
+    let env = Env::default();
+    let s = setup(&env);
+    let client = &s.client;
+
+    client.set_ttl_config(&TtlConfig {
+        instance_threshold: 50_000,
+        instance_extend_to: 100_000,
+        account_threshold: 9_000,
+        account_extend_to: 10_000,
+    });
+
+    let user = funded_user(&env, &s);
+    client.mint(&user, &1_000, &PiCoinSource::Mining);
+    next_ledger(&env);
+    let id = client.create_proposal(&user, &description(&env));
+    client.mint(&user, &1_000, &PiCoinSource::Mining);
+
+    let checkpoint = |index| {
+        DataKey::Checkpoint(CheckpointDataKey {
+            owner: user.clone(),
+            index,
+        })
+    };
+
+    // bump_accounts leaves the older checkpoint to expire
+    env.ledger().with_mut(|li| li.sequence_number += 2_000);
+    client.bump_accounts(&vec![&env, user.clone()]);
+    assert_eq!(account_ttl(&env, &s, &checkpoint(1)), 10_000);
+    assert_eq!(account_ttl(&env, &s, &checkpoint(0)), 7_999);
+
+    // The vote reads the snapshot from it, which extends it again
+    client.governance_vote(&user, &id, &VoteChoice::For);
+    assert_eq!(client.get_proposal(&id).votes_for, 1_000);
+    assert_eq!(account_ttl(&env, &s, &checkpoint(0)), 10_000);
+}
+
+#[test]
+fn test_invalid_ttl_config_rejected() {
+    let env = Env::default();
+    let s = setup(&env);
+
+    let result = s.client.try_set_ttl_config(&TtlConfig {
+        instance_threshold: 100,
+        instance_extend_to: 100,
+        account_threshold: 10,
+        account_extend_to: 20,
+    });
+    assert!(result.is_err());
+}
+
+// ---------- GOVERNANCE ----------
+
+fn description(env: &Env) -> BytesN<32> {
+    env.crypto()
+        .sha256(&Bytes::from_slice(env, b"PIP-1: raise collateral ratio"))
+        .into()
+}
+
+fn voters(env: &Env, s: &Setup, n: u32) -> Vec<Address> {
+    let mut voters = Vec::new(env);
+
+    for _ in 0..n {
+        let voter = funded_user(env, s);
+        s.client.mint(&voter, &1_000, &PiCoinSource::Mining);
+        voters.push_back(voter);
+    }
+    next_ledger(env);
+
+    voters
+}
+
+// Voting power is read from the ledger before a proposal is created.
+fn next_ledger(env: &Env) {
+    env.ledger().with_mut(|li| li.sequence_number += 1);
+}
+
+#[test]
+fn test_proposal_lifecycle() {
+    let env = Env::default();
+    let s = setup(&env);
+    let client = &s.client;
+
+    // The fifth voter holds power at the snapshot but only votes late;
+    // minting after the window would trip the stale oracle instead.
+    let v = voters(&env, &s, 5);
+    let (alice, bob) = (v.get(0).unwrap(), v.get(1).unwrap());
+    let (carol, dave) = (v.get(2).unwrap(), v.get(3).unwrap());
+    let late = v.get(4).unwrap();
+
+    let id = client.create_proposal(&alice, &description(&env));
+    assert_eq!(id, 1);
+
+    client.governance_vote(&alice, &id, &VoteChoice::For);
+    client.governance_vote(&bob, &id, &VoteChoice::For);
+    client.governance_vote(&carol, &id, &VoteChoice::Against);
+    client.governance_vote(&dave, &id, &VoteChoice::Abstain);
+
+    // one vote per address
+    assert!(client
+        .try_governance_vote(&alice, &id, &VoteChoice::Against)
+        .is_err());
+    assert_eq!(client.get_vote(&id, &carol), Some(VoteChoice::Against));
+
+    // cannot finalize while the window is open
+    assert!(client.try_finalize_proposal(&id).is_err());
+
+    let end_time = client.get_proposal(&id).end_time;
+    env.ledger().with_mut(|li| li.timestamp = end_time);
+
+    assert!(client
+        .try_governance_vote(&late, &id, &VoteChoice::Against)
+        .is_err());
+
+    assert_eq!(client.finalize_proposal(&id), ProposalStatus::Passed);
+
+    let proposal = client.get_proposal(&id);
+    assert_eq!(proposal.votes_for, 2_000);
+    assert_eq!(proposal.votes_against, 1_000);
+    assert_eq!(proposal.votes_abstain, 1_000);
+    assert_eq!(proposal.status, ProposalStatus::Passed);
+
+    assert!(client.try_finalize_proposal(&id).is_err());
+}
+
+#[test]
+fn test_proposal_quorum_and_threshold() {
+    let env = Env::default();
+    let s = setup(&env);
+    let client = &s.client;
+
+    client.set_governance_config(&GovernanceConfig {
+        voting_period_secs: 100,
+        quorum: 3_000,
+        threshold_bps: 6_000,
+    });
+
+    let v = voters(&env, &s, 3);
+    let (alice, bob, carol) =
+        (v.get(0).unwrap(), v.get(1).unwrap(), v.get(2).unwrap());
+
+    // quorum: only two of the required three votes are cast
+    let short = client.create_proposal(&alice, &description(&env));
+    client.governance_vote(&alice, &short, &VoteChoice::For);
+    client.governance_vote(&bob, &short, &VoteChoice::For);
+
+    // threshold: 2 of 3 decisive votes is above 60%, 1 of 2 is not
+    let passed = client.create_proposal(&alice, &description(&env));
+    client.governance_vote(&alice, &passed, &VoteChoice::For);
+    client.governance_vote(&bob, &passed, &VoteChoice::For);
+    client.governance_vote(&carol, &passed, &VoteChoice::Against);
+
+    let rejected = client.create_proposal(&alice, &description(&env));
+    client.governance_vote(&alice, &rejected, &VoteChoice::For);
+    client.governance_vote(&bob, &rejected, &VoteChoice::Against);
+    client.governance_vote(&carol, &rejected, &VoteChoice::Abstain);
+
+    env.ledger().with_mut(|li| li.timestamp += 100);
+
+    assert_eq!(
+        client.finalize_proposal(&short),
+        ProposalStatus::QuorumNotMet
+    );
+    assert_eq!(client.finalize_proposal(&passed), ProposalStatus::Passed);
+    assert_eq!(
+        client.finalize_proposal(&rejected),
+        ProposalStatus::Rejected
+    );
+
+    let invalid = GovernanceConfig {
+        voting_period_secs: 100,
+        quorum: 3_000,
+        threshold_bps: 4_000,
+    };
+    assert!(client.try_set_governance_config(&invalid).is_err());
+}
+
+#[test]
+fn test_source_weighted_voting_power() {
+    let env = Env::default();
+    let s = setup(&env);
+    let client = &s.client;
+
+    client.set_source_weight(&PiCoinSource::Mining, &30_000);
+    client.set_source_weight(&PiCoinSource::P2P, &5_000);
+    assert!(client
+        .try_set_source_weight(&PiCoinSource::Invalid, &10_000)
+        .is_err());
+
+    let miner = funded_user(&env, &s);
+    let trader = funded_user(&env, &s);
+    let sybil = funded_user(&env, &s);
+    let receiver = Address::generate(&env);
+
+    client.mint(&miner, &1_000, &PiCoinSource::Mining);
+    client.mint(&trader, &1_000, &PiCoinSource::P2P);
+    client.mint(&trader, &500, &PiCoinSource::Rewards);
+    client.mint(&sybil, &1, &PiCoinSource::P2P);
+    next_ledger(&env);
+
+    let id = client.create_proposal(&miner, &description(&env));
+    assert_eq!(client.voting_power(&miner, &id), 3_000);
+    assert_eq!(client.voting_power(&trader, &id), 1_000);
+
+    // dust rounds down to nothing
+    assert!(client
+        .try_governance_vote(&sybil, &id, &VoteChoice::For)
+        .is_err());
+
+    // tokens moved after the snapshot keep their original owner's power
+    client.transfer(&miner, &receiver, &1_000);
+    assert!(client
+        .try_governance_vote(&receiver, &id, &VoteChoice::For)
+        .is_err());
+
+    client.governance_vote(&miner, &id, &VoteChoice::Against);
+    client.governance_vote(&trader, &id, &VoteChoice::For);
+
+    let proposal = client.get_proposal(&id);
+    assert_eq!(proposal.votes_against, 3_000);
+    assert_eq!(proposal.votes_for, 1_000);
+
+    // weight changes only apply to later proposals
+    client.set_source_weight(&PiCoinSource::P2P, &10_000);
+    assert_eq!(client.voting_power(&trader, &id), 1_000);
+}
+
+#[test]
+fn test_proposal_requires_provenance() {
+    let env = Env::default();
+    let s = setup(&env);
+
+    let holder = funded_user(&env, &s);
+    s.client.mint(&holder, &1_000, &PiCoinSource::Mining);
+    next_ledger(&env);
+    let id = s.client.create_proposal(&holder, &description(&env));
+
+    let stranger = Address::generate(&env);
+    assert_eq!(
+        s.client.try_create_proposal(&stranger, &description(&env)),
+        Err(Ok(PiCoinError::InvalidSource))
+    );
+    assert_eq!(
+        s.client.try_governance_vote(&stranger, &id, &VoteChoice::For),
+        Err(Ok(PiCoinError::NoVotingPower))
+    );
+}
+
+// ---------- EVENTS ----------
+
+fn emitted<T, D>(env: &Env, s: &Setup, topics: T, data: D) -> bool
+where
+    T: IntoVal<Env, Vec<Val>>,
+    D: IntoVal<Env, Val>,
+{
+    let topics: Vec<Val> = topics.into_val(env);
+    let data: Val = data.into_val(env);
+
+    let topics = match xdr::ScVal::try_from_val(env, &topics.to_val()) {
+        Ok(xdr::ScVal::Vec(Some(topics))) => topics.0,
+        _ => return false,
+    };
+    let expected = xdr::ContractEventBody::V0(xdr::ContractEventV0 {
+        topics,
+        data: xdr::ScVal::try_from_val(env, &data).unwrap(),
+    });
+
+    env.events()
+        .all()
+        .filter_by_contract(&s.contract_id)
+        .events()
+        .iter()
+        .any(|event| event.body == expected)
+}
+
+#[test]
+fn test_token_events() {
+    let env = Env::default();
+    let s = setup(&env);
+    let client = &s.client;
+
+    let user = funded_user(&env, &s);
+    let spender = Address::generate(&env);
+    let receiver = Address::generate(&env);
+
+    client.mint(&user, &1_000, &PiCoinSource::Mining);
+    assert!(emitted(
+        &env,
+        &s,
+        (symbol_short!("mint"), user.clone()),
+        1_000_i128,
+    ));
+    assert!(emitted(
+        &env,
+        &s,
+        (symbol_short!("prov"), user.clone()),
+        vec![
+            &env,
+            ProvenanceLot { source: PiCoinSource::Mining, amount: 1_000 },
+        ],
+    ));
+
+    client.transfer(&user, &receiver, &300);
+    assert!(emitted(
+        &env,
+        &s,
+        (symbol_short!("transfer"), user.clone(), receiver.clone()),
+        300_i128,
+    ));
+
+    client.approve(&user, &spender, &200, &1_000);
+    assert!(emitted(
+        &env,
+        &s,
+        (symbol_short!("approve"), user.clone(), spender.clone()),
+        (200_i128, 1_000_u32),
+    ));
+
+    client.burn(&user, &100);
+    assert!(emitted(&env, &s, (symbol_short!("burn"), user.clone()), 100_i128));
+}
+
+#[test]
+fn test_config_and_governance_events() {
+    let env = Env::default();
+    let s = setup(&env);
+    let client = &s.client;
+
+    client.set_collateral_ratio(&20_000);
+    assert!(emitted(
+        &env,
+        &s,
+        (symbol_short!("config"), symbol_short!("coll_rat")),
+        20_000_u32,
+    ));
+
+    let user = funded_user(&env, &s);
+    client.mint(&user, &1_000, &PiCoinSource::Rewards);
+    next_ledger(&env);
+
+    let id = client.create_proposal(&user, &description(&env));
+    client.governance_vote(&user, &id, &VoteChoice::For);
+    assert!(emitted(
+        &env,
+        &s,
+        (symbol_short!("gov"), symbol_short!("vote"), user.clone()),
+        (id, VoteChoice::For),
+    ));
+}
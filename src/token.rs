@@ -0,0 +1,124 @@
+use soroban_sdk::{Env, Address, Vec};
+
+use crate::provenance::Provenance;
+use crate::storage::{ProvenanceLot, Storage};
+use crate::error::PiCoinError;
+
+pub const DECIMALS: u32 = 7;
+pub const NAME: &str = "Pi Coin";
+pub const SYMBOL: &str = "PI";
+
+pub struct Token;
+
+impl Token {
+    pub fn check_amount(amount: i128) -> Result<(), PiCoinError> {
+        if amount < 0 {
+            return Err(PiCoinError::NegativeAmount);
+        }
+
+        Ok(())
+    }
+
+    // ---------- TRANSFER GATE ----------
+    pub fn check_transfer_gate(
+        env: &Env,
+        from: &Address,
+    ) -> Result<(), PiCoinError> {
+        if !Storage::has_zkp_base(env) {
+            return Err(PiCoinError::InvalidProvenance);
+        }
+
+        Storage::get_provenance(env, from)?;
+
+        Ok(())
+    }
+
+    // ---------- BALANCES ----------
+    /// Debits `amount` and returns the provenance lots it was drawn from.
+    pub fn spend_balance(
+        env: &Env,
+        addr: &Address,
+        amount: i128,
+    ) -> Result<Vec<ProvenanceLot>, PiCoinError> {
+        let balance = Storage::get_balance(env, addr);
+
+        if balance < amount {
+            return Err(PiCoinError::InsufficientBalance);
+        }
+
+        Storage::set_balance(env, addr, balance - amount);
+
+        Ok(Provenance::debit(env, addr, amount))
+    }
+
+    pub fn receive_balance(env: &Env, addr: &Address, amount: i128) {
+        let balance = Storage::get_balance(env, addr);
+
+        Storage::set_balance(env, addr, balance + amount);
+    }
+
+    pub fn move_balance(
+        env: &Env,
+        from: &Address,
+        to: &Address,
+        amount: i128,
+    ) -> Result<(), PiCoinError> {
+        let lots = Self::spend_balance(env, from, amount)?;
+        Self::receive_balance(env, to, amount);
+        Provenance::credit_lots(env, to, &lots);
+
+        Ok(())
+    }
+
+    // ---------- SUPPLY ----------
+    pub fn increase_supply(env: &Env, amount: i128) -> Result<(), PiCoinError> {
+        let mut data = Storage::get_data(env);
+
+        let circulating = data
+            .circulating_supply
+            .checked_add(amount)
+            .ok_or(PiCoinError::SupplyCapExceeded)?;
+
+        if circulating > data.total_supply {
+            return Err(PiCoinError::SupplyCapExceeded);
+        }
+
+        data.circulating_supply = circulating;
+        Storage::set_data(env, &data);
+
+        Ok(())
+    }
+
+    pub fn decrease_supply(env: &Env, amount: i128) {
+        let mut data = Storage::get_data(env);
+
+        data.circulating_supply -= amount;
+        Storage::set_data(env, &data);
+    }
+
+    // ---------- ALLOWANCES ----------
+    pub fn spend_allowance(
+        env: &Env,
+        from: &Address,
+        spender: &Address,
+        amount: i128,
+    ) -> Result<(), PiCoinError> {
+        let allowance = Storage::get_allowance(env, from, spender);
+
+        if allowance.amount < amount {
+            return Err(PiCoinError::InsufficientAllowance);
+        }
+
+        if amount > 0 {
+            Storage::set_allowance(
+                env,
+                from,
+                spender,
+                allowance.amount - amount,
+                allowance.expiration_ledger,
+            )?;
+        }
+
+        Ok(())
+    }
+}
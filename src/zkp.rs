@@ -0,0 +1,107 @@
+use soroban_sdk::{
+    contracttype, vec, xdr::ToXdr, Env, Address, Bytes, BytesN, Vec,
+    crypto::bls12_381::{Fr, G1Affine, G2Affine},
+};
+
+use crate::storage::Storage;
+use crate::error::PiCoinError;
+
+/// Groth16 verifying key over BLS12-381. `ic` holds one point per public
+/// input plus the constant term at index 0.
+#[contracttype]
+#[derive(Clone)]
+pub struct VerifyingKey {
+    pub alpha: G1Affine,
+    pub beta: G2Affine,
+    pub gamma: G2Affine,
+    pub delta: G2Affine,
+    pub ic: Vec<G1Affine>,
+}
+
+#[contracttype]
+#[derive(Clone)]
+pub struct Groth16Proof {
+    pub a: G1Affine,
+    pub b: G2Affine,
+    pub c: G1Affine,
+}
+
+pub struct Zkp;
+
+impl Zkp {
+    /// Last public input of a `transfer_with_proof` proof. Ties the proof to
+    /// this contract, both parties and the amount.
+    pub fn transfer_binding(
+        env: &Env,
+        from: &Address,
+        to: &Address,
+        amount: i128,
+    ) -> Fr {
+        let mut payload = Bytes::new(env);
+        payload.append(&env.current_contract_address().to_xdr(env));
+        payload.append(&from.clone().to_xdr(env));
+        payload.append(&to.clone().to_xdr(env));
+        payload.extend_from_array(&amount.to_be_bytes());
+
+        // Clearing the top byte keeps the digest below the field modulus.
+        let mut digest = env.crypto().sha256(&payload).to_array();
+        digest[0] = 0;
+
+        Fr::from_bytes(BytesN::from_array(env, &digest))
+    }
+
+    /// Replay key of a proof: the public input just before the binding.
+    /// The circuit derives it from the spender's secret, so re-randomising
+    /// the proof, which changes its bytes, still yields the same nullifier.
+    pub fn nullifier(public_inputs: &Vec<Fr>) -> Option<BytesN<32>> {
+        let count = public_inputs.len();
+        if count < 2 {
+            return None;
+        }
+
+        public_inputs.get(count - 2).map(|input| input.to_bytes())
+    }
+
+    pub fn verify(
+        env: &Env,
+        proof: &Groth16Proof,
+        public_inputs: &Vec<Fr>,
+    ) -> Result<(), PiCoinError> {
+        let vk = Storage::get_verifying_key(env)
+            .ok_or(PiCoinError::VerifyingKeyNotSet)?;
+
+        if public_inputs.len() + 1 != vk.ic.len() {
+            return Err(PiCoinError::MalformedProof);
+        }
+
+        let bls = env.crypto().bls12_381();
+
+        // vk_x = ic[0] + sum(input_i * ic[i + 1])
+        let mut vk_x = vk.ic.get(0).ok_or(PiCoinError::MalformedProof)?;
+        for (input, point) in public_inputs.iter().zip(vk.ic.iter().skip(1)) {
+            vk_x = bls.g1_add(&vk_x, &bls.g1_mul(&point, &input));
+        }
+
+        // e(-A, B) * e(alpha, beta) * e(vk_x, gamma) * e(C, delta) == 1
+        let g1_points = vec![
+            env,
+            -proof.a.clone(),
+            vk.alpha,
+            vk_x,
+            proof.c.clone(),
+        ];
+        let g2_points = vec![
+            env,
+            proof.b.clone(),
+            vk.beta,
+            vk.gamma,
+            vk.delta,
+        ];
+
+        if !bls.pairing_check(g1_points, g2_points) {
+            return Err(PiCoinError::InvalidProof);
+        }
+
+        Ok(())
+    }
+}